@@ -0,0 +1,136 @@
+//! Helps with a game played somewhere else, such as the official daily
+//! puzzle. Enter each guess with the colours it got back, `b`lack, `y`ellow
+//! or `g`reen per letter:
+//!
+//! ```text
+//! > crane bgybb
+//! ```
+//!
+//! `undo` takes back the last guess, `reset` starts over and `quit` exits.
+//!
+//! ```text
+//! wordle-assist [--words FILE] [--guesses FILE] [--length N] [--strategy NAME]
+//! ```
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use wordle::{Guess, Pattern, StrategyKind, WordSet};
+
+/// How many remaining answers to list after each guess.
+const SAMPLE_SIZE: usize = 12;
+
+fn report(set: &WordSet) {
+    let count = set.answer_count();
+    let sample: Vec<String> = set.answer_words().take(SAMPLE_SIZE).map(|word| word.to_string()).collect();
+    println!(
+        "{} answer{} left: {}{}",
+        count,
+        if count == 1 { "" } else { "s" },
+        sample.join(" "),
+        if count > SAMPLE_SIZE { " ..." } else { "" }
+    );
+    if count == 0 {
+        println!("No answers fit; check the colours or undo.");
+        return;
+    }
+    if let Some(best) = set.rank(1).first() {
+        println!(
+            "Suggestion: {} ({:.2} bits, {:.1} left on average)",
+            best.guess.to_string().to_ascii_uppercase(),
+            best.expected_info,
+            best.expected_remaining
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let mut words = PathBuf::from("sgb-words-trimmed.txt");
+    let mut guesses = PathBuf::from("sgb-words.txt");
+    let mut length = Guess::DEFAULT_LEN;
+    let mut strategy = StrategyKind::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let parsed = match (arg.as_str(), args.next()) {
+            ("--words", Some(value)) => {
+                words = PathBuf::from(value);
+                Ok(())
+            }
+            ("--guesses", Some(value)) => {
+                guesses = PathBuf::from(value);
+                Ok(())
+            }
+            ("--length", Some(value)) => value
+                .parse()
+                .map(|n| length = n)
+                .map_err(|_| format!("invalid length {}", value)),
+            ("--strategy", Some(value)) => StrategyKind::parse(&value)
+                .map(|kind| strategy = kind)
+                .ok_or(format!("unknown strategy {}", value)),
+            _ => Err("usage: wordle-assist [--words FILE] [--guesses FILE] [--length N] [--strategy NAME]".to_string()),
+        };
+        if let Err(e) = parsed {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    let mut set = match WordSet::load_lists(&words, &guesses, length) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Could not load the word lists: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    set.set_strategy(strategy.build());
+
+    // Every earlier position, so `undo` can step back through them.
+    let mut history: Vec<WordSet> = Vec::new();
+    report(&set);
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            return ExitCode::SUCCESS;
+        }
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens[..] {
+            [] => continue,
+            ["quit"] | ["exit"] => return ExitCode::SUCCESS,
+            ["undo"] => match history.pop() {
+                Some(previous) => set = previous,
+                None => {
+                    println!("Nothing to undo.");
+                    continue;
+                }
+            },
+            ["reset"] => {
+                if let Some(first) = history.first() {
+                    set = first.clone();
+                    history.clear();
+                }
+            }
+            [guess, pattern] => {
+                let Some(guess) = Guess::parse(guess).filter(|guess| guess.length() == length) else {
+                    println!("{} is not a {} letter word.", guess, length);
+                    continue;
+                };
+                let Some(pattern) = Pattern::parse(pattern).filter(|pattern| pattern.length() == length) else {
+                    println!("Colours are {} of b, y or g, e.g. bgybb.", length);
+                    continue;
+                };
+                if pattern.is_solved() {
+                    println!("Solved in {}!", history.len() + 1);
+                }
+                history.push(set.clone());
+                set.reduce(&guess, &pattern);
+            }
+            _ => {
+                println!("Enter a guess and its colours (crane bgybb), undo, reset or quit.");
+                continue;
+            }
+        }
+        report(&set);
+    }
+}
@@ -0,0 +1,219 @@
+//! Plays a strategy against every answer, or a seeded sample of them, and
+//! reports how many guesses it needed.
+//!
+//! ```text
+//! wordle-bench [--words FILE] [--guesses FILE] [--length N] [--strategy NAME]
+//!              [--sample N] [--seed S] [--max-guesses N] [--worst N]
+//!              [--csv FILE] [--json FILE]
+//! ```
+//!
+//! `--words` lists the answers and `--guesses` the words allowed as guesses;
+//! only words of `--length` letters, five by default, are used.
+//!
+//! The mean counts a failed game as one guess more than the limit.
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+use wordle::{Guess, Pattern, StrategyKind, WordSet};
+
+struct Options {
+    words: PathBuf,
+    guesses: PathBuf,
+    length: usize,
+    strategy: StrategyKind,
+    sample: Option<usize>,
+    seed: u64,
+    max_guesses: usize,
+    worst: usize,
+    csv: Option<PathBuf>,
+    json: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct Game {
+    answer: Guess,
+    guesses: Vec<Guess>,
+    solved: bool,
+}
+
+#[derive(Serialize)]
+struct Report {
+    strategy: String,
+    words: String,
+    games: usize,
+    seed: Option<u64>,
+    max_guesses: usize,
+    mean_guesses: f64,
+    /// Games solved in 1, 2, … `max_guesses` guesses.
+    histogram: Vec<usize>,
+    failures: usize,
+    worst: Vec<Game>,
+    seconds: f64,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        words: PathBuf::from("sgb-words-trimmed.txt"),
+        guesses: PathBuf::from("sgb-words.txt"),
+        length: Guess::DEFAULT_LEN,
+        strategy: StrategyKind::default(),
+        sample: None,
+        seed: 0,
+        max_guesses: 6,
+        worst: 10,
+        csv: None,
+        json: None,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} needs a value", arg))?;
+        let number = || value.parse::<usize>().map_err(|_| format!("{} needs a number", arg));
+        match arg.as_str() {
+            "--words" => options.words = PathBuf::from(&value),
+            "--guesses" => options.guesses = PathBuf::from(&value),
+            "--length" => options.length = number()?,
+            "--strategy" => {
+                options.strategy =
+                    StrategyKind::parse(&value).ok_or(format!("unknown strategy {}", value))?
+            }
+            "--sample" => options.sample = Some(number()?),
+            "--seed" => options.seed = number()? as u64,
+            "--max-guesses" => options.max_guesses = number()?,
+            "--worst" => options.worst = number()?,
+            "--csv" => options.csv = Some(PathBuf::from(&value)),
+            "--json" => options.json = Some(PathBuf::from(&value)),
+            _ => return Err(format!("unknown option {}", arg)),
+        }
+    }
+    if options.max_guesses < 1 {
+        return Err("--max-guesses must be at least 1".to_string());
+    }
+    Ok(options)
+}
+
+/// Plays one game, starting from the already computed opener.
+fn play(set: &WordSet, opener: Guess, answer: Guess, max_guesses: usize) -> Game {
+    let mut set = set.clone();
+    let mut guesses = Vec::new();
+    let mut guess = opener;
+    loop {
+        guesses.push(guess);
+        let pattern = Pattern::score(&guess, &answer);
+        if pattern.is_solved() || guesses.len() == max_guesses {
+            return Game {
+                answer,
+                solved: pattern.is_solved(),
+                guesses,
+            };
+        }
+        set.reduce(&guess, &pattern);
+        guess = set.rank(1)[0].guess;
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut set = match WordSet::load_lists(&options.words, &options.guesses, options.length) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Could not load the word lists: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    set.set_strategy(options.strategy.build());
+
+    let mut answers: Vec<Guess> = set.answer_words().collect();
+    if let Some(sample) = options.sample {
+        let mut rng = StdRng::seed_from_u64(options.seed);
+        answers = answers.choose_multiple(&mut rng, sample).copied().collect();
+    }
+
+    let start = Instant::now();
+    let opener = set.rank(1)[0].guess;
+    let mut games: Vec<Game> = answers
+        .par_iter()
+        .map(|&answer| play(&set, opener, answer, options.max_guesses))
+        .collect();
+    let seconds = start.elapsed().as_secs_f64();
+
+    let mut histogram = vec![0; options.max_guesses];
+    let mut failures = 0;
+    let mut total = 0;
+    for game in &games {
+        if game.solved {
+            histogram[game.guesses.len() - 1] += 1;
+            total += game.guesses.len();
+        } else {
+            failures += 1;
+            total += options.max_guesses + 1;
+        }
+    }
+
+    if let Some(path) = &options.csv {
+        let mut csv = "answer,guesses,solved,path\n".to_string();
+        for game in &games {
+            let path: Vec<String> = game.guesses.iter().map(|guess| guess.to_string()).collect();
+            csv.push_str(&format!("{},{},{},{}\n", game.answer, game.guesses.len(), game.solved, path.join(" ")));
+        }
+        if let Err(e) = fs::write(path, csv) {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    games.sort_by(|a, b| {
+        // Failures first, then the longest games.
+        a.solved
+            .cmp(&b.solved)
+            .then(b.guesses.len().cmp(&a.guesses.len()))
+            .then(a.answer.to_string().cmp(&b.answer.to_string()))
+    });
+    games.truncate(options.worst);
+    let report = Report {
+        strategy: options.strategy.to_string(),
+        words: options.words.display().to_string(),
+        games: answers.len(),
+        seed: options.sample.map(|_| options.seed),
+        max_guesses: options.max_guesses,
+        mean_guesses: total as f64 / answers.len().max(1) as f64,
+        histogram,
+        failures,
+        worst: games,
+        seconds,
+    };
+
+    println!("Strategy: {} ({}, opener {})", report.strategy, report.words, opener);
+    println!("Games: {}", report.games);
+    println!("Mean guesses: {:.4}", report.mean_guesses);
+    for (i, count) in report.histogram.iter().enumerate() {
+        println!("{:>2}: {:>6}", i + 1, count);
+    }
+    println!(" X: {:>6}", report.failures);
+    println!("Worst:");
+    for game in &report.worst {
+        let path: Vec<String> = game.guesses.iter().map(|guess| guess.to_string()).collect();
+        println!("  {} {}{}", game.answer, path.join(" "), if game.solved { "" } else { " X" });
+    }
+    println!("Time: {:.2}s", report.seconds);
+
+    if let Some(path) = &options.json
+        && let Err(e) = fs::write(path, serde_json::to_string_pretty(&report).unwrap())
+    {
+        eprintln!("Could not write {}: {}", path.display(), e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,73 @@
+//! Writes the decision tree a strategy follows from an opener.
+//!
+//! ```text
+//! wordle-tree [--words FILE] [--guesses FILE] [--strategy NAME] [--json FILE] [--text FILE] OPENER
+//! ```
+//!
+//! `--words` lists the answers and `--guesses` the words allowed as guesses;
+//! only words as long as the opener are used.
+//! With neither `--json` nor `--text`, the text form is printed.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use wordle::{DecisionTree, Guess, StrategyKind, WordSet};
+
+fn main() -> ExitCode {
+    let mut words = PathBuf::from("sgb-words-trimmed.txt");
+    let mut guesses = PathBuf::from("sgb-words.txt");
+    let mut strategy = StrategyKind::default();
+    let mut json = None;
+    let mut text = None;
+    let mut opener = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        let parsed = match arg.as_str() {
+            "--words" => value().map(|v| words = v.into()),
+            "--guesses" => value().map(|v| guesses = v.into()),
+            "--json" => value().map(|v| json = Some(PathBuf::from(v))),
+            "--text" => value().map(|v| text = Some(PathBuf::from(v))),
+            "--strategy" => value().and_then(|v| {
+                StrategyKind::parse(&v)
+                    .map(|kind| strategy = kind)
+                    .ok_or(format!("unknown strategy {}", v))
+            }),
+            _ => Guess::parse(&arg)
+                .map(|guess| opener = Some(guess))
+                .ok_or(format!("invalid opener {}", arg)),
+        };
+        if let Err(e) = parsed {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    let Some(opener) = opener else {
+        eprintln!("usage: wordle-tree [--words FILE] [--guesses FILE] [--strategy NAME] [--json FILE] [--text FILE] OPENER");
+        return ExitCode::FAILURE;
+    };
+
+    let mut set = match WordSet::load_lists(&words, &guesses, opener.length()) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("Could not load the word lists: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    set.set_strategy(strategy.build());
+    let tree = DecisionTree::build(&set, opener);
+
+    if json.is_none() && text.is_none() {
+        print!("{}", tree.to_text());
+    }
+    for (path, contents) in [(json, tree.to_json()), (text, tree.to_text())] {
+        if let Some(path) = path
+            && let Err(e) = fs::write(&path, contents)
+        {
+            eprintln!("Could not write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,262 @@
+//! Plays Wordle in a terminal, for SSH sessions and machines without a
+//! display. Takes the same keys as the window: letters, Backspace and Enter,
+//! plus Esc to quit.
+//!
+//! `--daily` plays today's puzzle and `--seed N` puzzle number N, so that
+//! people can compare games on the same word.
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use rand::seq::IteratorRandom;
+use std::io::{self, Write};
+use std::path::Path;
+use wordle::{Feedback, Game, GameStatus, Guess, GuessStats, Rating, WordSet, daily_answers, rate, todays_puzzle};
+
+const GREY: Color = Color::Rgb { r: 0x88, g: 0x88, b: 0x88 };
+const DARK_GREY: Color = Color::Rgb { r: 0x44, g: 0x44, b: 0x44 };
+const RED: Color = Color::Rgb { r: 0xFF, g: 0x45, b: 0x00 };
+const YELLOW: Color = Color::Rgb { r: 0xFF, g: 0xCE, b: 0x1B };
+const GREEN: Color = Color::Rgb { r: 0x04, g: 0x63, b: 0x07 };
+const WHITE: Color = Color::Rgb { r: 0xFF, g: 0xFF, b: 0xFF };
+
+const SUGGESTION_COUNT: usize = 10;
+const KEYBOARD_ROWS: [(&str, u16); 3] = [("QWERTYUIOP", 0), ("ASDFGHJKL", 2), ("ZXCVBNM", 6)];
+
+fn feedback_color(feedback: Feedback) -> Color {
+    match feedback {
+        Feedback::Absent => DARK_GREY,
+        Feedback::Present => YELLOW,
+        Feedback::Correct => GREEN,
+    }
+}
+
+struct App {
+    game: Game,
+    /// Number of the daily puzzle being played, if it is one.
+    puzzle: Option<u64>,
+    typed: Vec<char>,
+    /// Skill and luck of each guess, out of 99.
+    ratings: Vec<Rating>,
+    ranking: Vec<GuessStats>,
+    is_thinking: bool,
+}
+
+impl App {
+    fn new(game: Game, puzzle: Option<u64>) -> App {
+        App {
+            game,
+            puzzle,
+            typed: Vec::new(),
+            ratings: Vec::new(),
+            ranking: Vec::new(),
+            is_thinking: true,
+        }
+    }
+    fn suggest(&mut self) {
+        self.ranking = self.game.word_set().rank(SUGGESTION_COUNT);
+        self.is_thinking = false;
+    }
+    /// Applies a key press; returns whether the solver needs to run again.
+    fn key(&mut self, code: KeyCode) -> bool {
+        if self.game.status() != GameStatus::InProgress {
+            return false;
+        }
+        match code {
+            KeyCode::Char(c) if c.is_ascii_alphabetic() && self.typed.len() < self.length() => {
+                self.typed.push(c.to_ascii_uppercase());
+            }
+            KeyCode::Backspace => {
+                self.typed.pop();
+            }
+            KeyCode::Enter if self.typed.len() == self.length() => {
+                let guess = Guess::new(&self.typed);
+                let before = self.game.word_set().clone();
+                let turn = self.game.play(guess);
+                self.ratings.push(rate(&before, &guess, &turn.pattern));
+                self.typed.clear();
+                self.is_thinking = true;
+                return true;
+            }
+            _ => {}
+        }
+        false
+    }
+    fn length(&self) -> usize {
+        self.game.word_set().word_length()
+    }
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        // Four columns per tile, then the skill and luck figures.
+        let skill_x = 6 + 4 * self.length() as u16;
+        let keyboard_y = 4 + self.game.max_guesses() as u16;
+        queue!(out, Clear(ClearType::All), MoveTo(2, 0))?;
+        let (title, title_color) = match self.game.status() {
+            GameStatus::InProgress => match self.puzzle {
+                Some(number) => (format!("WORDLE #{}", number), WHITE),
+                None => ("WORDLE".to_string(), WHITE),
+            },
+            GameStatus::Won => (self.answer_title(), GREEN),
+            GameStatus::Lost => (self.answer_title(), RED),
+        };
+        queue!(out, SetForegroundColor(title_color), Print(title), ResetColor)?;
+
+        queue!(out, MoveTo(skill_x, 1), SetForegroundColor(GREEN), Print("Skill"))?;
+        queue!(out, MoveTo(skill_x + 7, 1), SetForegroundColor(RED), Print("Luck"), ResetColor)?;
+        let turns = self.game.turns();
+        for row in 0..self.game.max_guesses() {
+            let y = 2 + row as u16;
+            queue!(out, MoveTo(2, y))?;
+            for i in 0..self.length() {
+                let (c, color) = if let Some(turn) = turns.get(row) {
+                    (turn.guess.chars()[i].to_ascii_uppercase(), feedback_color(turn.pattern[i]))
+                } else if row == turns.len() && self.game.status() == GameStatus::InProgress {
+                    (self.typed.get(i).copied().unwrap_or(' '), GREY)
+                } else {
+                    (' ', DARK_GREY)
+                };
+                tile(out, c, color)?;
+            }
+            if let Some(rating) = self.ratings.get(row) {
+                let skill = rating.skill.map_or("?".to_string(), |skill| skill.to_string());
+                queue!(out, MoveTo(skill_x, y), SetForegroundColor(GREEN), Print(skill))?;
+                queue!(out, MoveTo(skill_x + 7, y), SetForegroundColor(RED), Print(rating.luck))?;
+                queue!(out, ResetColor)?;
+            }
+        }
+
+        let letters = self.game.letters();
+        for (row, (keys, indent)) in KEYBOARD_ROWS.iter().enumerate() {
+            queue!(out, MoveTo(2 + indent, keyboard_y + row as u16))?;
+            for c in keys.chars() {
+                let color = letters.get(&c.to_ascii_lowercase()).map_or(GREY, |&f| feedback_color(f));
+                tile(out, c, color)?;
+            }
+        }
+
+        queue!(out, MoveTo(skill_x + 20, 1), SetForegroundColor(WHITE), Print("Suggestions"), ResetColor)?;
+        if self.is_thinking {
+            queue!(out, MoveTo(skill_x + 20, 2), Print("thinking\u{2026}"))?;
+        } else {
+            for (i, stats) in self.ranking.iter().enumerate() {
+                queue!(
+                    out,
+                    MoveTo(skill_x + 20, 2 + i as u16),
+                    Print(format!(
+                        "{:>2}. {}{} {:.2} bits, {:.1} left, worst {}",
+                        i + 1,
+                        stats.guess.to_string().to_ascii_uppercase(),
+                        if stats.could_be_answer { "*" } else { " " },
+                        stats.expected_info,
+                        stats.expected_remaining,
+                        stats.worst_case
+                    ))
+                )?;
+            }
+        }
+        queue!(
+            out,
+            MoveTo(2, keyboard_y + 4),
+            SetForegroundColor(GREY),
+            Print(format!(
+                "{} answers left. Type a word, Enter to guess, Esc to quit.",
+                self.game.word_set().answer_count()
+            )),
+            ResetColor
+        )?;
+        out.flush()
+    }
+    fn answer_title(&self) -> String {
+        let secret = self.game.secret().expect("the terminal game always has a secret");
+        format!("Answer: {}", secret.to_string().to_ascii_uppercase())
+    }
+}
+
+fn tile(out: &mut impl Write, c: char, color: Color) -> io::Result<()> {
+    queue!(
+        out,
+        SetBackgroundColor(color),
+        SetForegroundColor(WHITE),
+        Print(format!(" {} ", c)),
+        ResetColor,
+        Print(" ")
+    )
+}
+
+/// Puts the terminal back however the game ends.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<RawTerminal> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+const USAGE: &str = "usage: wordle-tui [--daily | --seed N]";
+
+/// The puzzle asked for on the command line, if any.
+fn puzzle_from_args() -> Result<Option<u64>, String> {
+    let mut puzzle = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--daily" => puzzle = Some(todays_puzzle()),
+            "--seed" => {
+                let value = args.next().ok_or(USAGE)?;
+                puzzle = Some(value.parse().map_err(|_| "--seed needs a number".to_string())?);
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(puzzle)
+}
+
+fn main() -> io::Result<()> {
+    let puzzle = puzzle_from_args().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    let word_set = WordSet::load_lists(
+        Path::new("sgb-words-trimmed.txt"),
+        Path::new("sgb-words.txt"),
+        Guess::DEFAULT_LEN,
+    )?;
+    let secret = match puzzle {
+        Some(number) => daily_answers(&word_set.answer_words().collect::<Vec<_>>(), number, 1)[0],
+        None => word_set.answer_words().choose(&mut rand::thread_rng()).unwrap(),
+    };
+    let mut app = App::new(Game::new(secret, word_set), puzzle);
+
+    let _terminal = RawTerminal::enter()?;
+    let mut out = io::stdout();
+    app.draw(&mut out)?;
+    app.suggest();
+    loop {
+        app.draw(&mut out)?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let quit = key.code == KeyCode::Esc
+            || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL));
+        if quit {
+            return Ok(());
+        }
+        if app.key(key.code) {
+            app.draw(&mut out)?;
+            app.suggest();
+        }
+    }
+}
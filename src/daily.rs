@@ -0,0 +1,82 @@
+use crate::feedback::Guess;
+use chrono::{Local, NaiveDate};
+
+/// The day of puzzle 0, when the original game started.
+const FIRST_DAY: NaiveDate = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+
+/// The number of the puzzle for `date`, counted in days from the first one.
+pub fn puzzle_number(date: NaiveDate) -> u64 {
+    date.signed_duration_since(FIRST_DAY).num_days().max(0) as u64
+}
+
+/// The number of today's puzzle, by the local calendar.
+pub fn todays_puzzle() -> u64 {
+    puzzle_number(Local::now().date_naive())
+}
+
+/// The `count` answers of puzzle `number`, the same for everyone with the
+/// same list; `count` is one per board.
+///
+/// The list is played through in an order shuffled by the puzzle number, so
+/// no word comes back until every other has had its day. Only the words and
+/// the number go into the shuffle, not `rand`, whose generators may change
+/// between versions.
+pub fn daily_answers(answers: &[Guess], number: u64, count: usize) -> Vec<Guess> {
+    let seed = list_seed(answers);
+    let mut order: Vec<u64> = Vec::new();
+    let mut round = None;
+    (0..count as u64)
+        .map(|i| {
+            let n = number * count as u64 + i;
+            let (this_round, place) = (n / answers.len() as u64, (n % answers.len() as u64) as usize);
+            if round != Some(this_round) {
+                round = Some(this_round);
+                order = (0..answers.len() as u64).collect();
+                let key = mix(seed ^ mix(this_round));
+                order.sort_by_key(|&word| mix(key ^ word));
+            }
+            answers[order[place] as usize]
+        })
+        .collect()
+}
+
+/// FNV-1a over the words, so a different list gives different puzzles.
+fn list_seed(answers: &[Guess]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for c in answers.iter().flat_map(|guess| guess.chars().iter().copied().chain([' '])) {
+        hash ^= c as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// The SplitMix64 finaliser: spreads nearby numbers far apart.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{WORDS, guesses};
+
+    #[test]
+    fn puzzles_are_numbered_by_day() {
+        assert_eq!(puzzle_number(FIRST_DAY), 0);
+        assert_eq!(puzzle_number(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()), 196);
+    }
+
+    #[test]
+    fn every_word_has_a_day_before_any_repeats() {
+        let words = guesses(&WORDS);
+        let mut week: Vec<String> = (0..6).flat_map(|n| daily_answers(&words, n, 1)).map(|word| word.to_string()).collect();
+        week.sort();
+        assert_eq!(week, ["allay", "bills", "fills", "hills", "llama", "speed"]);
+        assert_eq!(daily_answers(&words, 3, 1), daily_answers(&words, 3, 1));
+        // Boards of a multi-board puzzle are consecutive days of one list.
+        assert_eq!(daily_answers(&words, 1, 2), [daily_answers(&words, 2, 1), daily_answers(&words, 3, 1)].concat());
+    }
+}
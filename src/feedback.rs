@@ -0,0 +1,281 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::fmt::{Display, Formatter};
+use std::ops::Index;
+
+/// The grade given to a single letter of a guess.
+///
+/// Ordered from least to most informative, so the best thing known about a
+/// letter is simply the `max` of every grade it has received.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Feedback {
+    Absent,
+    Present,
+    Correct,
+}
+
+/// A guess of four to eight letters, stored in lowercase to match the word
+/// lists.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Guess {
+    chars: [char; Guess::MAX_LEN],
+    len: u8,
+}
+
+impl Guess {
+    pub const MIN_LEN: usize = 4;
+    pub const MAX_LEN: usize = 8;
+    /// The length of the original game, used unless another is chosen.
+    pub const DEFAULT_LEN: usize = 5;
+
+    /// # Panics
+    ///
+    /// Unless there are between [`Guess::MIN_LEN`] and [`Guess::MAX_LEN`]
+    /// letters.
+    pub fn new(chars: &[char]) -> Guess {
+        assert!((Guess::MIN_LEN..=Guess::MAX_LEN).contains(&chars.len()), "a guess has 4 to 8 letters");
+        let mut ans = Guess {
+            chars: [' '; Guess::MAX_LEN],
+            len: chars.len() as u8,
+        };
+        for (slot, c) in ans.chars.iter_mut().zip(chars) {
+            *slot = c.to_ascii_lowercase();
+        }
+        ans
+    }
+    /// Parses a word from a word list, returning `None` unless it is four to
+    /// eight ASCII letters.
+    pub fn parse(word: &str) -> Option<Guess> {
+        let chars: Vec<char> = word.trim().chars().collect();
+        let valid = (Guess::MIN_LEN..=Guess::MAX_LEN).contains(&chars.len())
+            && chars.iter().all(|c| c.is_ascii_alphabetic());
+        valid.then(|| Guess::new(&chars))
+    }
+    pub fn chars(&self) -> &[char] {
+        &self.chars[..self.len as usize]
+    }
+    pub fn length(&self) -> usize {
+        self.len as usize
+    }
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for c in self.chars() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// The feedback for every letter of a guess.
+///
+/// Written as one letter per position, `b`lack for absent, `y`ellow for
+/// present and `g`reen for correct, so `crane` against `react` is `ygybg`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pattern {
+    feedback: [Feedback; Guess::MAX_LEN],
+    len: u8,
+}
+
+impl Pattern {
+    /// Number of distinct patterns for words of `length` letters, i.e.
+    /// `3^length`. The all-green pattern has the last id.
+    pub fn count(length: usize) -> usize {
+        3usize.pow(length as u32)
+    }
+
+    /// # Panics
+    ///
+    /// Unless there are between [`Guess::MIN_LEN`] and [`Guess::MAX_LEN`]
+    /// grades.
+    pub fn new(feedback: &[Feedback]) -> Pattern {
+        assert!((Guess::MIN_LEN..=Guess::MAX_LEN).contains(&feedback.len()), "a guess has 4 to 8 letters");
+        let mut ans = Pattern {
+            feedback: [Feedback::Absent; Guess::MAX_LEN],
+            len: feedback.len() as u8,
+        };
+        ans.feedback[..feedback.len()].copy_from_slice(feedback);
+        ans
+    }
+    /// Grades `guess` against `answer` the way the official game does.
+    ///
+    /// Exact matches are marked first. Each remaining letter is then marked
+    /// present only while the answer still has unmatched copies of it, so a
+    /// repeated letter is never credited more often than it occurs.
+    ///
+    /// Both words must be the same length.
+    pub fn score(guess: &Guess, answer: &Guess) -> Pattern {
+        let (guess, answer) = (guess.chars(), answer.chars());
+        let mut ans = [Feedback::Absent; Guess::MAX_LEN];
+        let mut unmatched = [0u8; 26];
+        for i in 0..guess.len() {
+            if guess[i] == answer[i] {
+                ans[i] = Feedback::Correct;
+            } else if let Some(index) = letter_index(answer[i]) {
+                unmatched[index] += 1;
+            }
+        }
+        for i in 0..guess.len() {
+            if ans[i] == Feedback::Correct {
+                continue;
+            }
+            if let Some(index) = letter_index(guess[i])
+                && unmatched[index] > 0
+            {
+                unmatched[index] -= 1;
+                ans[i] = Feedback::Present;
+            }
+        }
+        Pattern {
+            feedback: ans,
+            len: guess.len() as u8,
+        }
+    }
+    /// Parses the `bygbb` notation, ignoring case.
+    pub fn parse(text: &str) -> Option<Pattern> {
+        let feedback = text
+            .trim()
+            .chars()
+            .map(|c| match c.to_ascii_lowercase() {
+                'b' => Some(Feedback::Absent),
+                'y' => Some(Feedback::Present),
+                'g' => Some(Feedback::Correct),
+                _ => None,
+            })
+            .collect::<Option<Vec<Feedback>>>()?;
+        (Guess::MIN_LEN..=Guess::MAX_LEN)
+            .contains(&feedback.len())
+            .then(|| Pattern::new(&feedback))
+    }
+    pub fn feedback(&self) -> &[Feedback] {
+        &self.feedback[..self.len as usize]
+    }
+    pub fn length(&self) -> usize {
+        self.len as usize
+    }
+    pub fn is_solved(&self) -> bool {
+        self.feedback().iter().all(|&f| f == Feedback::Correct)
+    }
+    /// Base-3 encoding of the pattern, with the first letter as the least
+    /// significant digit. Always less than [`Pattern::count`] of its length.
+    pub fn id(&self) -> u16 {
+        self.feedback().iter().rev().fold(0, |id, f| {
+            id * 3
+                + match f {
+                    Feedback::Absent => 0,
+                    Feedback::Present => 1,
+                    Feedback::Correct => 2,
+                }
+        })
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for feedback in self.feedback() {
+            let c = match feedback {
+                Feedback::Absent => 'b',
+                Feedback::Present => 'y',
+                Feedback::Correct => 'g',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+// Guesses and patterns are stored in their short text forms, so saved files
+// stay readable and can be used as JSON map keys.
+impl Serialize for Guess {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Guess {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Guess, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Guess::parse(&text).ok_or_else(|| de::Error::custom(format!("invalid guess {:?}", text)))
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Pattern, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Pattern::parse(&text).ok_or_else(|| de::Error::custom(format!("invalid pattern {:?}", text)))
+    }
+}
+
+fn letter_index(c: char) -> Option<usize> {
+    c.is_ascii_lowercase().then(|| c as usize - 'a' as usize)
+}
+
+impl Index<usize> for Pattern {
+    type Output = Feedback;
+
+    fn index(&self, index: usize) -> &Feedback {
+        &self.feedback()[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Feedback::{Absent as A, Correct as C, Present as P};
+
+    fn score(guess: &str, answer: &str) -> Vec<Feedback> {
+        Pattern::score(&Guess::parse(guess).unwrap(), &Guess::parse(answer).unwrap()).feedback().to_vec()
+    }
+
+    #[test]
+    fn repeated_guess_letter_counts_green_first() {
+        assert_eq!(score("eerie", "there"), [P, A, P, A, C]);
+        assert_eq!(score("llama", "allay"), [P, C, P, A, P]);
+    }
+
+    #[test]
+    fn repeated_guess_letter_limited_by_answer() {
+        assert_eq!(score("speed", "abide"), [A, A, P, A, P]);
+        assert_eq!(score("speed", "steal"), [C, A, C, A, A]);
+        assert_eq!(score("eerie", "hello"), [A, C, A, A, A]);
+    }
+
+    #[test]
+    fn repeated_answer_letter() {
+        assert_eq!(score("lapse", "llama"), [C, P, A, A, A]);
+        assert_eq!(score("speed", "speed"), [C; 5]);
+    }
+
+    #[test]
+    fn pattern_text_round_trip() {
+        let pattern = Pattern::new(&[P, C, A, A, P]);
+        assert_eq!(pattern.to_string(), "ygbby");
+        assert_eq!(Pattern::parse("YGBBY"), Some(pattern));
+        assert_eq!(Pattern::parse("ygb"), None);
+        assert_eq!(Pattern::parse("ygbbx"), None);
+    }
+
+    #[test]
+    fn pattern_ids_are_distinct() {
+        assert_eq!(Pattern::new(&[A; 5]).id(), 0);
+        assert_eq!(Pattern::new(&[C; 5]).id() as usize, Pattern::count(5) - 1);
+        assert_eq!(Pattern::new(&[P, A, A, A, A]).id(), 1);
+        assert_eq!(Pattern::new(&[A, P, A, A, A]).id(), 3);
+        assert_eq!(Pattern::new(&[C; 8]).id() as usize, Pattern::count(8) - 1);
+    }
+
+    #[test]
+    fn other_word_lengths() {
+        assert_eq!(score("rats", "star"), [P, P, P, P]);
+        assert_eq!(score("between", "beneath"), [C, C, P, A, P, A, P]);
+        assert_eq!(Guess::parse("cat"), None);
+        assert_eq!(Guess::parse("fabulous").unwrap().length(), 8);
+        assert_eq!(Pattern::parse("gggggg").unwrap().length(), 6);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::feedback::{Feedback, Guess, Pattern};
+use crate::strategy::Strategy;
+use crate::suggestion::WordSet;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// A graded guess, with how far it narrowed down the answers.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Turn {
+    pub guess: Guess,
+    pub pattern: Pattern,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+}
+
+impl Turn {
+    /// Bits of information the reply actually gave.
+    pub fn actual_info(&self) -> f64 {
+        ((self.candidates_before as f64) / (self.candidates_after as f64)).log(2.0)
+    }
+}
+
+/// One game against a secret word, independent of how it is drawn.
+///
+/// An assist game has no secret: it follows a game played somewhere else,
+/// with the feedback for each guess entered by hand.
+#[derive(Clone)]
+pub struct Game {
+    secret: Option<Guess>,
+    word_set: WordSet,
+    turns: Vec<Turn>,
+    max_guesses: usize,
+}
+
+impl Game {
+    pub const MAX_GUESSES: usize = 6;
+
+    pub fn new(secret: Guess, word_set: WordSet) -> Game {
+        Game {
+            secret: Some(secret),
+            word_set,
+            turns: Vec::new(),
+            max_guesses: Game::MAX_GUESSES,
+        }
+    }
+    pub fn assist(word_set: WordSet) -> Game {
+        Game {
+            secret: None,
+            word_set,
+            turns: Vec::new(),
+            max_guesses: Game::MAX_GUESSES,
+        }
+    }
+    /// The secret word, or `None` in an assist game.
+    pub fn secret(&self) -> Option<Guess> {
+        self.secret
+    }
+    /// The answers still consistent with every turn so far.
+    pub fn word_set(&self) -> &WordSet {
+        &self.word_set
+    }
+    /// Plays by the hard-mode rule from the next guess on.
+    pub fn set_hard_mode(&mut self, on: bool) {
+        self.word_set.set_hard_mode(on);
+    }
+    /// Why `guess` may not be played under hard mode, if it may not.
+    pub fn check_hard_mode(&self, guess: &Guess) -> Result<(), String> {
+        self.word_set.hard_mode().map_or(Ok(()), |hard| hard.check(guess))
+    }
+    pub fn set_strategy(&mut self, strategy: Arc<dyn Strategy>) {
+        self.word_set.set_strategy(strategy);
+    }
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+    /// Allows `max_guesses` guesses instead of [`Game::MAX_GUESSES`].
+    pub fn set_max_guesses(&mut self, max_guesses: usize) {
+        self.max_guesses = max_guesses;
+    }
+    pub fn status(&self) -> GameStatus {
+        match self.turns.last() {
+            Some(turn) if turn.pattern.is_solved() => GameStatus::Won,
+            _ if self.turns.len() == self.max_guesses => GameStatus::Lost,
+            _ => GameStatus::InProgress,
+        }
+    }
+    /// Grades `guess` against the secret and narrows down the answers.
+    ///
+    /// # Panics
+    ///
+    /// In an assist game, which has no secret to grade against; use
+    /// [`Game::record`] instead.
+    pub fn play(&mut self, guess: Guess) -> Turn {
+        let secret = self.secret.expect("assist games have no secret to grade against");
+        self.record(guess, Pattern::score(&guess, &secret))
+    }
+    /// Records a guess with feedback from elsewhere and narrows down the
+    /// answers.
+    pub fn record(&mut self, guess: Guess, pattern: Pattern) -> Turn {
+        let candidates_before = self.word_set.answer_count();
+        self.word_set.reduce(&guess, &pattern);
+        let turn = Turn {
+            guess,
+            pattern,
+            candidates_before,
+            candidates_after: self.word_set.answer_count(),
+        };
+        self.turns.push(turn);
+        turn
+    }
+    /// The best feedback each letter has received so far.
+    pub fn letters(&self) -> BTreeMap<char, Feedback> {
+        let mut letters = BTreeMap::new();
+        for turn in &self.turns {
+            for (&c, &feedback) in turn.guess.chars().iter().zip(turn.pattern.feedback()) {
+                let best = letters.entry(c).or_insert(feedback);
+                *best = (*best).max(feedback);
+            }
+        }
+        letters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{guess, words};
+
+    fn game(secret: &str) -> Game {
+        Game::new(guess(secret), words())
+    }
+
+    #[test]
+    fn game_tracks_turns_and_letters() {
+        let mut game = game("hills");
+        let turn = game.play(guess("llama"));
+        assert_eq!((turn.candidates_before, turn.candidates_after), (6, 3));
+        assert_eq!(game.status(), GameStatus::InProgress);
+        assert_eq!(game.letters()[&'l'], Feedback::Present);
+        game.play(guess("hills"));
+        assert_eq!(game.status(), GameStatus::Won);
+        assert_eq!(game.letters()[&'l'], Feedback::Correct);
+    }
+
+    #[test]
+    fn game_is_lost_after_max_guesses() {
+        let mut game = game("hills");
+        for _ in 0..Game::MAX_GUESSES {
+            game.play(guess("speed"));
+        }
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn assist_game_uses_recorded_feedback() {
+        let mut game = Game::assist(game("hills").word_set().clone());
+        assert_eq!(game.secret(), None);
+        let pattern = Pattern::score(&guess("llama"), &guess("bills"));
+        assert_eq!(game.record(guess("llama"), pattern).candidates_after, 3);
+        assert_eq!(game.status(), GameStatus::InProgress);
+    }
+}
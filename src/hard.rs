@@ -0,0 +1,95 @@
+use crate::feedback::{Feedback, Guess, Pattern};
+
+/// The hard-mode rule: every revealed hint must be used again. Green letters
+/// stay where they were found, and yellow letters appear somewhere in every
+/// later guess.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct HardMode {
+    /// Letters known to sit at each position.
+    fixed: [Option<char>; Guess::MAX_LEN],
+    /// How many of each letter every guess must contain, at least.
+    required: [usize; 26],
+}
+
+impl HardMode {
+    pub fn new() -> HardMode {
+        HardMode::default()
+    }
+    /// Takes in the hints revealed by one guess.
+    pub fn add(&mut self, guess: &Guess, pattern: &Pattern) {
+        let mut counts = [0usize; 26];
+        for (i, (&c, &feedback)) in guess.chars().iter().zip(pattern.feedback()).enumerate() {
+            if feedback == Feedback::Correct {
+                self.fixed[i] = Some(c);
+            }
+            if feedback != Feedback::Absent {
+                counts[letter_index(c)] += 1;
+            }
+        }
+        for (required, count) in self.required.iter_mut().zip(counts) {
+            *required = (*required).max(count);
+        }
+    }
+    /// Why `guess` breaks the rule, if it does, in the words of the official
+    /// game: "3rd letter must be R" or "Guess must contain S".
+    pub fn check(&self, guess: &Guess) -> Result<(), String> {
+        let chars = guess.chars();
+        for (i, fixed) in self.fixed.iter().enumerate() {
+            if let Some(c) = *fixed
+                && chars.get(i) != Some(&c)
+            {
+                return Err(format!("{} letter must be {}", ordinal(i + 1), c.to_ascii_uppercase()));
+            }
+        }
+        for (i, &required) in self.required.iter().enumerate() {
+            let c = (b'a' + i as u8) as char;
+            if chars.iter().filter(|&&x| x == c).count() < required {
+                return Err(match required {
+                    1 => format!("Guess must contain {}", c.to_ascii_uppercase()),
+                    n => format!("Guess must contain {} {}s", n, c.to_ascii_uppercase()),
+                });
+            }
+        }
+        Ok(())
+    }
+    pub fn allows(&self, guess: &Guess) -> bool {
+        self.check(guess).is_ok()
+    }
+}
+
+fn letter_index(c: char) -> usize {
+    c as usize - 'a' as usize
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match n {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::guess;
+
+    #[test]
+    fn hints_must_be_reused() {
+        let mut hard = HardMode::new();
+        hard.add(&guess("tares"), &Pattern::parse("bbgyb").unwrap());
+        assert_eq!(hard.check(&guess("lions")), Err("3rd letter must be R".to_string()));
+        assert_eq!(hard.check(&guess("corny")), Err("Guess must contain E".to_string()));
+        assert_eq!(hard.check(&guess("fired")), Ok(()));
+    }
+
+    #[test]
+    fn repeated_hints_need_repeated_letters() {
+        let mut hard = HardMode::new();
+        hard.add(&guess("speed"), &Pattern::parse("bbyyb").unwrap());
+        assert_eq!(hard.check(&guess("hello")), Err("Guess must contain 2 Es".to_string()));
+        assert!(hard.allows(&guess("eerie")));
+    }
+}
@@ -0,0 +1,40 @@
+//! Headless Wordle core: the feedback model and the candidate solver.
+//!
+//! Nothing in here depends on a GUI, so the solver can be driven from tests,
+//! benchmarks or other front ends.
+
+mod daily;
+mod feedback;
+mod game;
+mod hard;
+mod lookahead;
+mod matrix;
+mod rating;
+mod review;
+mod save;
+mod share;
+mod stats;
+mod strategy;
+mod suggestion;
+#[cfg(test)]
+mod testing;
+mod tree;
+mod words;
+
+pub use crate::daily::{daily_answers, puzzle_number, todays_puzzle};
+pub use crate::feedback::{Feedback, Guess, Pattern};
+pub use crate::game::{Game, GameStatus, Turn};
+pub use crate::hard::HardMode;
+pub use crate::lookahead::Lookahead;
+pub use crate::matrix::PatternMatrix;
+pub use crate::rating::{GameSummary, Rating, rate, rate_game, solver_game};
+pub use crate::review::{TurnReview, review};
+pub use crate::save::{SavedBoard, SavedGame};
+pub use crate::share::share_text;
+pub use crate::stats::{GameRecord, History};
+pub use crate::strategy::{
+    AnswersOnly, Entropy, ExpectedRemaining, LetterFrequency, Minimax, Strategy, StrategyKind, rank_boards,
+};
+pub use crate::suggestion::{GuessStats, WordSet};
+pub use crate::tree::{DecisionTree, Node};
+pub use crate::words::{parse_word_list, read_word_list};
@@ -0,0 +1,201 @@
+use crate::feedback::Pattern;
+use crate::hard::HardMode;
+use crate::matrix::PatternMatrix;
+use crate::strategy::{Entropy, Strategy};
+use crate::suggestion::{GuessStats, WordSet};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Candidate sets at most this large are searched to the end, ignoring the
+/// depth limit, since every useful guess splits them quickly.
+const EXACT_LIMIT: usize = 12;
+
+/// Searches guesses and feedback buckets to minimise the expected number of
+/// guesses needed to solve, rather than maximising information one step
+/// ahead.
+///
+/// Only the `breadth` most informative guesses are tried at each node, and
+/// below `depth` guesses the remaining cost of a large set is estimated.
+/// In hard mode the guesses further down use the hints found on the way.
+/// Results are memoised on the candidate set and those hints, so a solver
+/// kept across turns gets cheaper as the game goes on.
+pub struct Lookahead {
+    depth: usize,
+    breadth: usize,
+    memo: Mutex<HashMap<MemoKey, (usize, f64)>>,
+}
+
+/// The candidates of a search node, and its hard-mode hints if any.
+type MemoKey = (Vec<usize>, Option<HardMode>);
+
+impl Lookahead {
+    pub fn new(depth: usize, breadth: usize) -> Lookahead {
+        Lookahead {
+            depth,
+            breadth,
+            memo: Mutex::new(HashMap::new()),
+        }
+    }
+    /// Expected number of guesses, this one included, to solve `set` by
+    /// playing `guess` next.
+    pub fn expected_guesses(&self, set: &WordSet, guess: usize) -> f64 {
+        self.cost(set, set.hard_mode(), &set.answers, guess, self.depth)
+    }
+    /// Expected number of guesses to solve `answers`, a subset of `set`, from
+    /// here, with `hard` the hints so far in hard mode. A search called off
+    /// through `set` gives up with infinity.
+    fn solve(&self, set: &WordSet, hard: Option<&HardMode>, answers: &[usize], depth: usize) -> f64 {
+        if set.is_cancelled() {
+            return f64::INFINITY;
+        }
+        match answers.len() {
+            0 => return 0.0,
+            1 => return 1.0,
+            2 => return 1.5,
+            _ => {}
+        }
+        let exact = answers.len() <= EXACT_LIMIT;
+        if depth == 0 && !exact {
+            return estimate(answers.len());
+        }
+        let budget = if exact { usize::MAX } else { depth };
+        let key = (answers.to_vec(), hard.cloned());
+        if let Some(&(searched, value)) = self.memo.lock().unwrap().get(&key)
+            && searched >= budget
+        {
+            return value;
+        }
+        let best = candidates(&set.matrix, hard, answers, self.breadth)
+            .into_iter()
+            .map(|g| self.cost(set, hard, answers, g, depth.saturating_sub(1)))
+            .fold(f64::INFINITY, f64::min);
+        // A search cut short must not be remembered as finished.
+        if set.is_cancelled() {
+            return best;
+        }
+        self.memo.lock().unwrap().insert(key, (budget, best));
+        best
+    }
+    fn cost(&self, set: &WordSet, hard: Option<&HardMode>, answers: &[usize], guess: usize, depth: usize) -> f64 {
+        let row = set.matrix.row(guess);
+        let mut buckets = vec![Vec::new(); Pattern::count(set.word_length())];
+        for &a in answers {
+            buckets[row[a] as usize].push(a);
+        }
+        // A guess that leaves every answer in one bucket teaches nothing, and
+        // would recurse forever.
+        if buckets.iter().any(|bucket| bucket.len() == answers.len()) && answers.len() > 1 {
+            return f64::INFINITY;
+        }
+        let total = answers.len() as f64;
+        1.0 + buckets[..buckets.len() - 1]
+            .iter()
+            .filter(|bucket| !bucket.is_empty())
+            .map(|bucket| {
+                // Every answer in a bucket gives the same reply, so any one
+                // shows the hints it reveals.
+                let hints = hard.map(|hard| {
+                    let (guess, answer) = (set.matrix.guesses()[guess], set.matrix.answers()[bucket[0]]);
+                    let mut hints = hard.clone();
+                    hints.add(&guess, &Pattern::score(&guess, &answer));
+                    hints
+                });
+                bucket.len() as f64 / total * self.solve(set, hints.as_ref(), bucket, depth)
+            })
+            .sum::<f64>()
+    }
+}
+
+impl Strategy for Lookahead {
+    fn name(&self) -> &'static str {
+        "Lookahead"
+    }
+    /// The `n` best guesses by expected guesses to solve, drawn from the most
+    /// informative ones.
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        let mut ranking: Vec<(usize, GuessStats)> = Entropy
+            .rank(set, n.max(self.breadth))
+            .into_par_iter()
+            .map(|mut stats| {
+                let g = set.matrix.guess_index(&stats.guess).unwrap();
+                stats.expected_guesses = Some(self.expected_guesses(set, g));
+                (g, stats)
+            })
+            .collect();
+        ranking.sort_by(|(i, a), (j, b)| {
+            a.expected_guesses
+                .unwrap()
+                .total_cmp(&b.expected_guesses.unwrap())
+                .then(b.could_be_answer.cmp(&a.could_be_answer))
+                .then(i.cmp(j))
+        });
+        ranking.into_iter().take(n).map(|(_, stats)| stats).collect()
+    }
+}
+
+/// Rough expected guesses for `n` answers when the search stops: about one
+/// guess per three bits still to learn, plus the final guess.
+fn estimate(n: usize) -> f64 {
+    1.0 + (n as f64).log2() / 3.0
+}
+
+/// The `breadth` guesses that split `answers` into the most buckets, which is
+/// cheap to count and a good stand-in for information. Only guesses `hard`
+/// allows are counted.
+fn candidates(matrix: &PatternMatrix, hard: Option<&HardMode>, answers: &[usize], breadth: usize) -> Vec<usize> {
+    let count = Pattern::count(matrix.length());
+    let mut scored: Vec<(usize, usize)> = (0..matrix.guesses().len())
+        .filter(|&g| hard.is_none_or(|hard| hard.allows(&matrix.guesses()[g])))
+        .map(|g| {
+            let row = matrix.row(g);
+            let mut seen = vec![false; count];
+            let mut buckets = 0;
+            for &a in answers {
+                let id = row[a] as usize;
+                if !seen[id] {
+                    seen[id] = true;
+                    // Solving outright is worth a little more than a split.
+                    buckets += if id == count - 1 { 2 } else { 1 };
+                }
+            }
+            (g, buckets)
+        })
+        .collect();
+    scored.sort_by(|(i, a), (j, b)| b.cmp(a).then(i.cmp(j)));
+    scored.into_iter().take(breadth).map(|(g, _)| g).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{guess, guesses};
+    use std::sync::Arc;
+
+    #[test]
+    fn probe_word_beats_guessing_candidates() {
+        let answers = guesses(&["bills", "fills", "hills", "mills"]);
+        let mut words = answers.clone();
+        words.push(guess("fibhm"));
+        let set = WordSet::new(Arc::new(PatternMatrix::new(words, answers)));
+        let lookahead = Lookahead::new(2, 5);
+        assert_eq!(lookahead.expected_guesses(&set, 0), 2.5);
+        assert_eq!(lookahead.expected_guesses(&set, 4), 2.0);
+        let best = &lookahead.rank(&set, 1)[0];
+        assert_eq!(best.guess.to_string(), "fibhm");
+        assert_eq!(best.expected_guesses, Some(2.0));
+    }
+
+    #[test]
+    fn hard_mode_holds_deeper_guesses_to_the_hints() {
+        let answers = guesses(&["bills", "fills", "hills", "mills", "pills"]);
+        let mut words = answers.clone();
+        words.push(guess("fhmpy"));
+        let mut set = WordSet::new(Arc::new(PatternMatrix::new(words, answers)));
+        // After BILLS, FHMPY would split the other four, but hard mode keeps
+        // the ILLS it revealed and leaves only the answers to guess.
+        assert_eq!(Lookahead::new(2, 6).expected_guesses(&set, 0), 2.6);
+        set.set_hard_mode(true);
+        assert_eq!(Lookahead::new(2, 6).expected_guesses(&set, 0), 3.0);
+    }
+}
@@ -1,16 +1,29 @@
-mod suggestion;
-use crate::suggestion::WordSet;
+use wordle::{
+    Feedback, Game, GameRecord, GameStatus, Guess, GuessStats, History, Pattern, SavedGame, StrategyKind, Turn,
+    GameSummary, Rating, TurnReview, WordSet, daily_answers, rank_boards, rate, rate_game, review, share_text,
+    solver_game, todays_puzzle,
+};
 
+use chrono::Local;
 use iced::alignment::Horizontal;
 use iced::font::{Font, Weight};
 use iced::keyboard::{Key, Modifiers, key::Named};
-use iced::widget::{Column, Container, Row, button, column, container, row, stack, text};
-use iced::{color, Color, Element, Padding, Renderer, Subscription, Theme, keyboard};
+use iced::widget::{
+    Column, Container, Row, button, column, container, mouse_area, pick_list, row, scrollable,
+    stack, text, tooltip,
+};
+use iced::futures::channel::oneshot;
+use iced::task::{self, Task};
+use iced::{clipboard, color, window, Color, Element, Padding, Size, Subscription, keyboard};
 use rand::seq::IteratorRandom;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
-use std::fs;
-use std::sync::LazyLock;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 pub static GREY: LazyLock<Color, fn() -> Color> =
     LazyLock::new(|| Color::from_rgb8(0x88, 0x88, 0x88));
@@ -27,10 +40,19 @@ pub static BLACK: LazyLock<Color, fn() -> Color> =
 pub static WHITE: LazyLock<Color, fn() -> Color> =
     LazyLock::new(|| Color::from_rgb8(0xFF, 0xFF, 0xFF));
 
+/// Colour a graded letter is drawn with, on the board and the keyboard.
+fn feedback_color(feedback: Feedback) -> Color {
+    match feedback {
+        Feedback::Absent => *DARK_GREY,
+        Feedback::Present => *YELLOW,
+        Feedback::Correct => *GREEN,
+    }
+}
+
 #[derive(Debug, Clone)]
 enum GameResult {
-    WIN,
-    LOSE,
+    Win,
+    Lose,
 }
 
 #[derive(Debug, Clone)]
@@ -39,113 +61,137 @@ enum Message {
     DeleteText,
     Enter,
     GameOver(GameResult),
+    SuggestionReady(u64, Vec<GuessStats>),
+    AnalysisReady(u64, usize, Rating),
+    SummaryReady(u64, GameSummary, Option<GameSummary>),
     ToggleSuggest,
-    ToggleAnalysis
+    ToggleAnalysis,
+    SelectStrategy(StrategyKind),
+    ToggleAssist,
+    CycleTile(usize),
+    NewGame,
+    ToggleFreeForm,
+    ToggleHardMode,
+    ToggleDaily,
+    ToggleStats,
+    GameRecorded(Box<GameRecord>),
+    ResumeGame,
+    AbandonGame,
+    Share,
+    ToggleReview,
+    ReviewReady(u64, Vec<TurnReview>),
+    ReviewTurn(usize),
+    SelectBoards(BoardCount),
+    Tick(Instant),
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 struct Entry {
-    chars: [char; 5],
-    colors: [Color; 5],
+    chars: Vec<char>,
+    feedback: Vec<Option<Feedback>>,
     is_active: bool,
     cursor: usize,
 }
 
-impl Default for Entry {
-    fn default() -> Entry {
+impl Entry {
+    fn new(length: usize) -> Entry {
         Entry {
-            chars: [' '; 5],
-            colors: [*DARK_GREY; 5],
+            chars: vec![' '; length],
+            feedback: vec![None; length],
             is_active: false,
             cursor: 0,
         }
     }
-}
-
-impl Entry {
-    fn from_chars(chars: [char; 5]) -> Entry {
-        Entry {
-            chars,
-            ..Entry::default()
+    fn color(&self, i: usize) -> Color {
+        match self.feedback[i] {
+            Some(feedback) => feedback_color(feedback),
+            None if self.is_active => *GREY,
+            None => *DARK_GREY,
         }
     }
-    fn view(&self) -> Element<'_, Message> {
-        row![
-            letter_block(self.chars[0], self.colors[0]),
-            letter_block(self.chars[1], self.colors[1]),
-            letter_block(self.chars[2], self.colors[2]),
-            letter_block(self.chars[3], self.colors[3]),
-            letter_block(self.chars[4], self.colors[4])
-        ]
+    /// Draws the row in tiles of `size`; `clickable` tiles cycle their colour
+    /// when pressed, for entering the feedback of a game played elsewhere.
+    fn view(&self, size: f32, clickable: bool) -> Element<'_, Message> {
+        Row::from_vec(
+            (0..self.chars.len())
+                .map(|i| {
+                    let block = letter_block(self.chars[i], self.color(i), size);
+                    if clickable {
+                        mouse_area(block).on_press(Message::CycleTile(i)).into()
+                    } else {
+                        block.into()
+                    }
+                })
+                .collect(),
+        )
         .spacing(5)
         .into()
     }
+    /// Moves a tile on to the next colour: grey, yellow, green, then back.
+    fn cycle(&mut self, i: usize) {
+        self.feedback[i] = Some(match self.feedback[i] {
+            None | Some(Feedback::Correct) => Feedback::Absent,
+            Some(Feedback::Absent) => Feedback::Present,
+            Some(Feedback::Present) => Feedback::Correct,
+        });
+    }
+    /// The feedback clicked in so far, with unclicked tiles counting as grey.
+    fn pattern(&self) -> Pattern {
+        let feedback: Vec<Feedback> = self.feedback.iter().map(|feedback| feedback.unwrap_or(Feedback::Absent)).collect();
+        Pattern::new(&feedback)
+    }
     fn update(&mut self, message: Message) {
         match message {
             Message::EnterText(c) => {
-                if c.is_ascii_alphabetic() && self.cursor < 5 {
+                if c.is_ascii_alphabetic() && self.cursor < self.chars.len() {
                     self.chars[self.cursor] = c;
                     self.cursor += 1;
                 }
             }
-            Message::DeleteText => {
-                if self.cursor > 0 {
-                    self.cursor -= 1;
-                    self.chars[self.cursor] = ' ';
-                }
+            Message::DeleteText if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.chars[self.cursor] = ' ';
+                self.feedback[self.cursor] = None;
             }
             _ => {}
         }
     }
 }
 
+/// How long a rejected row shakes for, and how far it moves either way.
+const SHAKE_TIME: Duration = Duration::from_millis(400);
+const SHAKE_DISTANCE: f32 = 12.0;
+/// How long a toast such as "Not in word list" stays up.
+const TOAST_TIME: Duration = Duration::from_millis(1500);
+
 // #[derive(Default)]
 struct EntrySet {
-    entries: [Entry; 6],
-    strings: [[char; 5]; 6],
+    entries: Vec<Entry>,
     active_entry: usize,
-    secret_word: String,
-    suggestion_word_bank: WordSet,
-}
-
-impl Default for EntrySet {
-    fn default() -> EntrySet {
-        let mut ans = EntrySet {
-            entries: [Entry::default(); 6],
-            strings: [[' '; 5]; 6],
-            active_entry: 0,
-            secret_word: fs::read_to_string("sgb-words-trimmed.txt")
-                .unwrap()
-                .split('\n')
-                .choose(&mut rand::thread_rng())
-                .unwrap()
-                .to_string()
-                .to_ascii_uppercase(),
-            suggestion_word_bank: WordSet {
-                words: fs::read_to_string("sgb-words-trimmed.txt")
-                    .unwrap()
-                    .split('\n')
-                    .into_iter()
-                    .map(|x| x.to_string())
-                    .collect(),
-                answer_words: fs::read_to_string("sgb-words-trimmed.txt")
-                    .unwrap()
-                    .split('\n')
-                    .into_iter()
-                    .map(|x| x.to_string())
-                    .collect(),
-            },
-        };
-        //println!("Suggestion: {}",ans.suggestion_word_bank.suggest());
-        println!("Suggestion: tares");
-        for i in 0..5 {
-            ans.entries[0].colors[i] = *GREY;
-        }
-        ans
-    }
+    game: Game,
 }
 
 impl EntrySet {
+    /// The board for `game`, with the rows of any turns it already has
+    /// filled in.
+    fn new(game: Game) -> EntrySet {
+        let mut entries = vec![Entry::new(game.word_set().word_length()); game.max_guesses()];
+        for (entry, turn) in entries.iter_mut().zip(game.turns()) {
+            entry.chars = turn.guess.chars().iter().map(|c| c.to_ascii_uppercase()).collect();
+            entry.cursor = entry.chars.len();
+            entry.feedback = turn.pattern.feedback().iter().copied().map(Some).collect();
+        }
+        let active_entry = game.turns().len().min(entries.len() - 1);
+        entries[active_entry].is_active = game.status() == GameStatus::InProgress;
+        EntrySet {
+            entries,
+            active_entry,
+            game,
+        }
+    }
+    fn is_assist(&self) -> bool {
+        self.game.secret().is_none()
+    }
     // fn from_strings(strings: [[char; 5]; 6]) -> EntrySet {
     //     let mut entries = [Entry::default(); 6];
     //     for i in 0..strings.len() {
@@ -153,59 +199,62 @@ impl EntrySet {
     //     }
     //     EntrySet {entries, strings, active_entry: 0}
     // }
-    fn view(&self) -> Element<'_, Message> {
-        column![
-            self.entries[0].view(),
-            self.entries[1].view(),
-            self.entries[2].view(),
-            self.entries[3].view(),
-            self.entries[4].view(),
-            self.entries[5].view()
-        ]
-        .spacing(10)
-        .padding(Padding {
-            top: 20.0,
-            right: 0.0,
-            bottom: 0.0,
-            left: 350.0,
-        })
+    /// Draws the rows in tiles of `size`, the active one moved `shake` to
+    /// the side.
+    fn view(&self, size: f32, shake: f32) -> Element<'_, Message> {
+        let in_progress = self.game.status() == GameStatus::InProgress;
+        let clickable = |i: usize| self.is_assist() && i == self.active_entry && in_progress;
+        Column::from_vec(
+            self.entries
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let offset = if i == self.active_entry && in_progress { shake } else { 0.0 };
+                    container(entry.view(size, clickable(i)))
+                        .padding(Padding {
+                            top: 0.0,
+                            right: SHAKE_DISTANCE - offset,
+                            bottom: 0.0,
+                            left: SHAKE_DISTANCE + offset,
+                        })
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(row_spacing(size))
         .into()
     }
-    fn grade(&mut self, grade_result: [(char, Color); 5]) {
-        self.entries[self.active_entry].colors = grade_result.map(|(_, color)| color);
+    /// Moves on to the next row, unless the board is finished.
+    fn advance(&mut self) {
+        if self.game.status() == GameStatus::InProgress {
+            self.entries[self.active_entry].is_active = false;
+            self.active_entry += 1;
+            self.entries[self.active_entry].is_active = true;
+        } else {
+            self.entries[self.active_entry].is_active = false;
+        }
+    }
+    fn grade(&mut self, pattern: &Pattern) {
+        self.entries[self.active_entry].feedback = pattern.feedback().iter().copied().map(Some).collect();
         // println!(
         //     "Grade: {:?} {:?} {:?} {:?} {:?}",
         //     self.colors[0], self.colors[1], self.colors[2], self.colors[3], self.colors[4]`
         // );
         
-        // let old_bank_length = self.suggestion_word_bank.answer_words.len();
-        // self.suggestion_word_bank.reduce(grade_result);
-        // let new_bank_length = self.suggestion_word_bank.answer_words.len();
-        // let info = ((old_bank_length as f64) / (new_bank_length as f64)).log(2.0);
-        // println!("Actual Info: {:.2}", info);
-        // println!(
-        //     "Reduced from {} words to {} words",
-        //     old_bank_length,
-        //     self.suggestion_word_bank.answer_words.len()
-        // );
-        
-        //let guess: String = grade_result.map(|(c, color)| c).iter().collect();
-        // self.suggestion_word_bank.words.retain(|x| *x != guess);
-        // println!("Reduced from {} words to {} words", old_bank_length, self.suggestion_word_bank.reduce(grade_result).words.len());
-
-        //println!("Suggestion: {}",self.suggestion_word_bank.suggest());
     }
 }
 
+/// The best grade of every letter on each board, drawn as one key split
+/// into a stripe per board.
 struct Keyboard {
-    state: BTreeMap<char, Color>,
+    state: BTreeMap<char, Vec<Option<Feedback>>>,
 }
 
-impl Default for Keyboard {
-    fn default() -> Self {
+impl Keyboard {
+    fn new(boards: usize) -> Self {
         let mut state = BTreeMap::new();
         for c in 'A'..='Z' {
-            state.insert(c, *GREY);
+            state.insert(c, vec![None; boards]);
         }
         Self { state }
     }
@@ -223,15 +272,15 @@ impl Keyboard {
 
         for i in 0..row1_chars.len() {
             let c = row1_chars.chars().nth(i).unwrap();
-            row1_set.push(letter_block(c, self.state.get(&c).unwrap().clone()).into())
+            row1_set.push(key_block(c, self.colors(c)))
         }
         for i in 0..row2_chars.len() {
             let c = row2_chars.chars().nth(i).unwrap();
-            row2_set.push(letter_block(c, self.state.get(&c).unwrap().clone()).into())
+            row2_set.push(key_block(c, self.colors(c)))
         }
         for i in 0..row3_chars.len() {
             let c = row3_chars.chars().nth(i).unwrap();
-            row3_set.push(letter_block(c, self.state.get(&c).unwrap().clone()).into())
+            row3_set.push(key_block(c, self.colors(c)))
         }
         let row1 = Row::from_vec(row1_set).spacing(5).padding(Padding {
             top: 30.0,
@@ -253,10 +302,17 @@ impl Keyboard {
         });
         column![row1, row2, row3].into()
     }
-    fn update(&mut self, message: Message) {}
-    fn grade(&mut self, grade_result: [(char, Color); 5]) {
-        for (c, color) in grade_result {
-            self.state.insert(c, color);
+    fn colors(&self, c: char) -> Vec<Color> {
+        self.state[&c]
+            .iter()
+            .map(|feedback| feedback.map_or(*GREY, feedback_color))
+            .collect()
+    }
+    fn grade(&mut self, board: usize, guess: &Guess, pattern: &Pattern) {
+        for (&c, &feedback) in guess.chars().iter().zip(pattern.feedback()) {
+            if let Some(keys) = self.state.get_mut(&c.to_ascii_uppercase()) {
+                keys[board] = keys[board].max(Some(feedback));
+            }
         }
     }
 }
@@ -326,7 +382,7 @@ impl SuggestionButton {
     }
     fn toggle(&mut self) {
         self.setting = !self.setting;
-        if self.text == "Show Suggestions".to_string() {
+        if self.text == "Show Suggestions" {
             self.text = "Hide Suggestions".to_string();
         } else {
             self.text = "Show Suggestions".to_string();
@@ -334,22 +390,75 @@ impl SuggestionButton {
     }
 }
 
+/// How many ranked guesses the suggestion list shows.
+const SUGGESTION_COUNT: usize = 10;
+
 struct SuggestionBox {
-    text: String,
-    suggestion: String,
+    ranking: Vec<GuessStats>,
+    is_thinking: bool,
+    is_displayed: bool,
 }
 
 impl Default for SuggestionBox {
     fn default() -> Self {
         SuggestionBox {
-            text: " ".to_string(),
-            suggestion: "...".to_string(),
+            ranking: Vec::new(),
+            is_thinking: true,
+            is_displayed: true,
         }
     }
 }
 impl SuggestionBox {
     fn view(&self) -> Element<'_, Message> {
-        container(text(self.text.clone()).size(50).color(*WHITE))
+        let content: Element<'_, Message> = if !self.is_displayed {
+            text(" ").size(50).into()
+        } else if self.is_thinking {
+            text("thinking\u{2026}").size(50).color(*WHITE).into()
+        } else {
+            let best = self.ranking.first().map_or("...".to_string(), |stats| {
+                stats.guess.to_string().to_ascii_uppercase()
+            });
+            let ranked_list = Column::from_vec(
+                self.ranking
+                    .iter()
+                    .enumerate()
+                    .map(|(i, stats)| {
+                        column![
+                            text(format!(
+                                "{}. {}{}",
+                                i + 1,
+                                stats.guess.to_string().to_ascii_uppercase(),
+                                if stats.could_be_answer { " *" } else { "" }
+                            ))
+                            .size(18)
+                            .color(*WHITE),
+                            text(match stats.expected_guesses {
+                                Some(guesses) => format!(
+                                    "{:.2} guesses, {:.2} bits, worst {}",
+                                    guesses, stats.expected_info, stats.worst_case
+                                ),
+                                None => format!(
+                                    "{:.2} bits, {:.1} left, worst {}",
+                                    stats.expected_info, stats.expected_remaining, stats.worst_case
+                                ),
+                            })
+                            .size(12)
+                            .color(*GREY)
+                        ]
+                        .into()
+                    })
+                    .collect(),
+            )
+            .spacing(6)
+            .width(200);
+            column![
+                text(best).size(50).color(*WHITE),
+                scrollable(ranked_list).height(260)
+            ]
+            .align_x(Horizontal::Right)
+            .into()
+        };
+        container(content)
             .width(1000)
             .align_x(Horizontal::Right)
             .padding(Padding {
@@ -361,11 +470,7 @@ impl SuggestionBox {
             .into()
     }
     fn set_box(&mut self, setting: bool) {
-        if setting {
-            self.text = self.suggestion.clone();
-        } else {
-            self.text = " ".to_string();
-        }
+        self.is_displayed = setting;
     }
 }
 
@@ -396,60 +501,658 @@ impl AnalysisButton {
     }
     fn toggle(&mut self) {
         self.setting = !self.setting;
-        if self.text == "Show Analysis".to_string() {
+        if self.text == "Show Analysis" {
             self.text = "Hide Analysis".to_string();
         } else {
             self.text = "Show Analysis".to_string();
         }
     }
 }
-#[derive(Copy, Clone)]
+struct FreeFormButton {
+    text: String,
+    setting: bool,
+}
+impl Default for FreeFormButton {
+    fn default() -> Self {
+        FreeFormButton {
+            text: "Allow Any Word".to_string(),
+            setting: false,
+        }
+    }
+}
+impl FreeFormButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(
+            button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleFreeForm),
+        )
+        .padding(Padding {
+            top: 5.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 20.0,
+        })
+        .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Dictionary Only" {
+            self.text = "Allow Any Word".to_string();
+        } else {
+            self.text = "Dictionary Only".to_string();
+        }
+    }
+}
+
+struct DailyButton {
+    text: String,
+    setting: bool,
+}
+impl Default for DailyButton {
+    fn default() -> Self {
+        DailyButton {
+            text: "Daily Puzzle".to_string(),
+            setting: false,
+        }
+    }
+}
+impl DailyButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleDaily))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Random Word" {
+            self.text = "Daily Puzzle".to_string();
+        } else {
+            self.text = "Random Word".to_string();
+        }
+    }
+}
+
+struct HardModeButton {
+    text: String,
+    setting: bool,
+}
+impl Default for HardModeButton {
+    fn default() -> Self {
+        HardModeButton {
+            text: "Hard Mode".to_string(),
+            setting: false,
+        }
+    }
+}
+impl HardModeButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(
+            button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleHardMode),
+        )
+        .padding(Padding {
+            top: 5.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 20.0,
+        })
+        .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Normal Mode" {
+            self.text = "Hard Mode".to_string();
+        } else {
+            self.text = "Normal Mode".to_string();
+        }
+    }
+}
+
+/// A short message over the board, such as a rejected guess.
+#[derive(Default)]
+struct Toast {
+    text: Option<String>,
+    shown_at: Option<Instant>,
+}
+impl Toast {
+    fn view(&self) -> Element<'_, Message> {
+        match &self.text {
+            Some(message) => container(
+                container(text(message.clone()).size(20).color(*BLACK))
+                    .padding(10)
+                    .style(|theme| {
+                        let mut x = container::rounded_box(theme);
+                        x.background = Some((*WHITE).into());
+                        x
+                    }),
+            )
+            .center_x(1000)
+            .padding(Padding {
+                top: 90.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 0.0,
+            })
+            .into(),
+            None => container(text("")).into(),
+        }
+    }
+    fn show(&mut self, message: &str, now: Instant) {
+        self.text = Some(message.to_string());
+        self.shown_at = Some(now);
+    }
+}
+
+struct StatsButton {
+    text: String,
+    setting: bool,
+}
+impl Default for StatsButton {
+    fn default() -> Self {
+        StatsButton {
+            text: "Statistics".to_string(),
+            setting: false,
+        }
+    }
+}
+impl StatsButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleStats))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Hide Statistics" {
+            self.text = "Statistics".to_string();
+        } else {
+            self.text = "Hide Statistics".to_string();
+        }
+    }
+}
+
+/// Every finished game, kept in a file between sessions, and the summary
+/// drawn over the board.
+struct StatsBox {
+    history: History,
+    path: PathBuf,
+    /// Rows of the guess distribution, however few were needed.
+    max_guesses: usize,
+    is_displayed: bool,
+}
+
+impl StatsBox {
+    fn record(&mut self, record: GameRecord) -> std::io::Result<()> {
+        self.history.append(&self.path, record)
+    }
+    fn view(&self) -> Element<'_, Message> {
+        if !self.is_displayed {
+            return container(text("")).into();
+        }
+        let figure = |value: usize, label: &'static str| {
+            column![text(value.to_string()).size(36).color(*WHITE), text(label).size(14).color(*WHITE)]
+                .align_x(Horizontal::Center)
+                .width(90)
+                .into()
+        };
+        let figures = Row::from_vec(vec![
+            figure(self.history.played(), "Played"),
+            figure(self.history.win_percent(), "Win %"),
+            figure(self.history.current_streak(), "Current Streak"),
+            figure(self.history.max_streak(), "Max Streak"),
+        ]);
+
+        // The bar of the game just finished stands out, as in the official
+        // game.
+        let latest = self.history.games().last().filter(|game| game.won).map(|game| game.guesses.len());
+        let distribution = self.history.distribution(self.max_guesses);
+        let most = distribution.iter().copied().max().unwrap_or(0).max(1);
+        let bars = Column::from_vec(
+            distribution
+                .iter()
+                .enumerate()
+                .map(|(i, &count)| {
+                    let color = if latest == Some(i + 1) { *GREEN } else { *GREY };
+                    row![
+                        text((i + 1).to_string()).size(16).color(*WHITE).width(20),
+                        container(text(count.to_string()).size(16).color(*WHITE))
+                            .width(30.0 + 280.0 * count as f32 / most as f32)
+                            .align_x(Horizontal::Right)
+                            .padding(Padding {
+                                top: 0.0,
+                                right: 5.0,
+                                bottom: 0.0,
+                                left: 0.0,
+                            })
+                            .style(move |_| container::Style {
+                                background: Some(color.into()),
+                                ..container::Style::default()
+                            })
+                    ]
+                    .into()
+                })
+                .collect(),
+        )
+        .spacing(4);
+
+        container(
+            container(
+                column![
+                    text("Statistics").size(30).color(*WHITE).font(Font {
+                        weight: Weight::Bold,
+                        ..Font::default()
+                    }),
+                    figures,
+                    text("Guess Distribution").size(20).color(*WHITE),
+                    bars
+                ]
+                .spacing(15)
+                .align_x(Horizontal::Center),
+            )
+            .padding(20)
+            .width(400)
+            .style(|theme| {
+                let mut x = container::rounded_box(theme);
+                x.background = Some((*DARK_GREY).into());
+                x
+            }),
+        )
+        .center_x(1000)
+        .padding(Padding {
+            top: 90.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .into()
+    }
+}
+
+/// Offers to pick up the game left unfinished last time.
+#[derive(Default)]
+struct ResumePrompt {
+    saved: Option<SavedGame>,
+}
+
+impl ResumePrompt {
+    fn view(&self) -> Element<'_, Message> {
+        let Some(saved) = &self.saved else {
+            return container(text("")).into();
+        };
+        let turns = saved.boards.iter().map(|board| board.guesses.len()).max().unwrap_or(0);
+        let question = format!(
+            "Resume your last game? {} guess{} played.",
+            turns,
+            if turns == 1 { "" } else { "es" }
+        );
+        container(
+            container(
+                column![
+                    text(question).size(20).color(*WHITE),
+                    row![
+                        button(text("Resume").size(20).color(*WHITE)).on_press(Message::ResumeGame),
+                        button(text("Abandon").size(20).color(*WHITE)).on_press(Message::AbandonGame)
+                    ]
+                    .spacing(20)
+                ]
+                .spacing(15)
+                .align_x(Horizontal::Center),
+            )
+            .padding(20)
+            .style(|theme| {
+                let mut x = container::rounded_box(theme);
+                x.background = Some((*DARK_GREY).into());
+                x
+            }),
+        )
+        .center_x(1000)
+        .padding(Padding {
+            top: 90.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .into()
+    }
+}
+
+/// Copies the result grid once the game is over.
+#[derive(Default)]
+struct ShareButton;
+impl ShareButton {
+    fn view(&self, game_over: bool) -> Element<'_, Message> {
+        if !game_over {
+            return container(text("")).into();
+        }
+        container(button(text("Share").size(20).color(*WHITE)).on_press(Message::Share))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+}
+
+struct ReviewButton {
+    text: String,
+    setting: bool,
+}
+impl Default for ReviewButton {
+    fn default() -> Self {
+        ReviewButton {
+            text: "Review".to_string(),
+            setting: false,
+        }
+    }
+}
+impl ReviewButton {
+    fn view(&self, game_over: bool) -> Element<'_, Message> {
+        if !game_over {
+            return container(text("")).into();
+        }
+        container(button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleReview))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Hide Review" {
+            self.text = "Review".to_string();
+        } else {
+            self.text = "Hide Review".to_string();
+        }
+    }
+}
+
+/// The finished game a turn at a time, beside what the solver would have
+/// played.
+#[derive(Default)]
+struct ReviewBox {
+    turns: Vec<TurnReview>,
+    current: usize,
+    is_displayed: bool,
+    is_thinking: bool,
+}
+
+/// Remaining answers listed by name before the rest are only counted.
+const REVIEW_WORDS: usize = 40;
+
+impl ReviewBox {
+    fn view(&self) -> Element<'_, Message> {
+        if !self.is_displayed {
+            return container(text("")).into();
+        }
+        let body: Element<'_, Message> = match self.turns.get(self.current) {
+            _ if self.is_thinking => text("Replaying the game\u{2026}").size(20).color(*WHITE).into(),
+            None => text("No guesses to review").size(20).color(*WHITE).into(),
+            Some(review) => {
+                let word = |guess: Guess| guess.to_string().to_ascii_uppercase();
+                let turn = review.turn;
+                let played = match &review.played {
+                    Some(stats) => format!("You played {}: {:.2} bits", word(turn.guess), stats.expected_info),
+                    None => format!("You played {}, which is not in the guess list", word(turn.guess)),
+                };
+                let verdict = match review.shortfall() {
+                    Some(short) if short < 0.005 => "As good as the solver's".to_string(),
+                    Some(short) => format!("{:.2} bits short of the solver's", short),
+                    None => String::new(),
+                };
+                let mut remaining: Vec<String> =
+                    review.remaining.iter().take(REVIEW_WORDS).map(|&guess| word(guess)).collect();
+                if review.remaining.len() > REVIEW_WORDS {
+                    remaining.push(format!("and {} more", review.remaining.len() - REVIEW_WORDS));
+                }
+                column![
+                    text(format!(
+                        "{} answers before, {} after: {:.2} bits gained",
+                        turn.candidates_before,
+                        turn.candidates_after,
+                        turn.actual_info()
+                    ))
+                    .size(18)
+                    .color(*WHITE),
+                    text(format!(
+                        "The solver would play {}: {:.2} bits",
+                        word(review.suggestion.guess),
+                        review.suggestion.expected_info
+                    ))
+                    .size(18)
+                    .color(*WHITE),
+                    text(played).size(18).color(*WHITE),
+                    text(verdict).size(18).color(*YELLOW),
+                    text(format!("Left: {}", remaining.join(" "))).size(16).color(*WHITE)
+                ]
+                .spacing(8)
+                .into()
+            }
+        };
+        let mut previous = button(text("Previous").size(18).color(*WHITE));
+        if self.current > 0 {
+            previous = previous.on_press(Message::ReviewTurn(self.current - 1));
+        }
+        let mut next = button(text("Next").size(18).color(*WHITE));
+        if self.current + 1 < self.turns.len() {
+            next = next.on_press(Message::ReviewTurn(self.current + 1));
+        }
+        let heading = format!("Turn {} of {}", (self.current + 1).min(self.turns.len()), self.turns.len());
+        container(
+            container(
+                column![
+                    row![previous, text(heading).size(24).color(*WHITE), next]
+                        .spacing(20)
+                        .align_y(iced::alignment::Vertical::Center),
+                    body
+                ]
+                .spacing(15)
+                .align_x(Horizontal::Center),
+            )
+            .padding(20)
+            .width(480)
+            .style(|theme| {
+                let mut x = container::rounded_box(theme);
+                x.background = Some((*DARK_GREY).into());
+                x
+            }),
+        )
+        .center_x(1000)
+        .padding(Padding {
+            top: 90.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 0.0,
+        })
+        .into()
+    }
+}
+
+#[derive(Default)]
+struct NewGameButton;
+impl NewGameButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(button(text("New Game").size(20).color(*WHITE)).on_press(Message::NewGame))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+}
+
+struct AssistButton {
+    text: String,
+    setting: bool,
+}
+impl Default for AssistButton {
+    fn default() -> Self {
+        AssistButton {
+            text: "Assist Mode".to_string(),
+            setting: false,
+        }
+    }
+}
+impl AssistButton {
+    fn view(&self) -> Element<'_, Message> {
+        container(
+            button(text(self.text.clone()).size(20).color(*WHITE)).on_press(Message::ToggleAssist),
+        )
+        .padding(Padding {
+            top: 5.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 20.0,
+        })
+        .into()
+    }
+    fn toggle(&mut self) {
+        self.setting = !self.setting;
+        if self.text == "Play Mode" {
+            self.text = "Assist Mode".to_string();
+        } else {
+            self.text = "Play Mode".to_string();
+        }
+    }
+}
+#[derive(Default)]
+struct StrategyPicker {
+    selected: StrategyKind,
+}
+impl StrategyPicker {
+    fn view(&self) -> Element<'_, Message> {
+        container(
+            pick_list(&StrategyKind::ALL[..], Some(self.selected), Message::SelectStrategy)
+                .text_size(20),
+        )
+        .padding(Padding {
+            top: 5.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 20.0,
+        })
+        .into()
+    }
+}
+
+/// How many secret words each guess is played against at once.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct BoardCount(usize);
+
+impl BoardCount {
+    const ALL: [BoardCount; 4] = [BoardCount(1), BoardCount(2), BoardCount(4), BoardCount(8)];
+}
+
+impl Default for BoardCount {
+    fn default() -> Self {
+        BoardCount(1)
+    }
+}
+
+impl Display for BoardCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            1 => write!(f, "Wordle"),
+            2 => write!(f, "Dordle"),
+            4 => write!(f, "Quordle"),
+            8 => write!(f, "Octordle"),
+            n => write!(f, "{} boards", n),
+        }
+    }
+}
+
+#[derive(Default)]
+struct BoardPicker {
+    selected: BoardCount,
+}
+impl BoardPicker {
+    fn view(&self) -> Element<'_, Message> {
+        container(pick_list(&BoardCount::ALL[..], Some(self.selected), Message::SelectBoards).text_size(20))
+            .padding(Padding {
+                top: 5.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 20.0,
+            })
+            .into()
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
 enum AnalysisFigure {
     Inactive,
-    Active(f64),
+    Active(u8),
     Unknown,
 }
 impl Display for AnalysisFigure {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             AnalysisFigure::Inactive => write!(f, " "),
-            AnalysisFigure::Active(x) => write!(f, "{:.2}", x),
+            AnalysisFigure::Active(x) => write!(f, "{}", x),
             AnalysisFigure::Unknown => write!(f, "?"),
         }
     }
 }
 
+const SKILL_TIP: &str = "How much the guess could be expected to narrow down the answers, \
+                         against the best guess there was: 99 is as good as it gets, 0 tells nothing apart. \
+                         ? for a word outside the guess list.";
+const LUCK_TIP: &str = "How the reply compares with every other reply the guess could have got: \
+                        99 left fewer answers than almost any other would have, 50 is ordinary.";
+
 struct AnalysisBox {
-    skill_values: [AnalysisFigure; 6],
-    luck_values: [AnalysisFigure; 6],
+    skill_values: Vec<AnalysisFigure>,
+    luck_values: Vec<AnalysisFigure>,
     is_displayed: bool,
+    /// Once the game is over: the player, then the solver on the same
+    /// secret, if there was one.
+    summary: Option<(GameSummary, Option<GameSummary>)>,
     //heuristic_table: HashMap<String, f64>
 }
 
-impl Default for AnalysisBox {
-    fn default() -> Self {
+impl AnalysisBox {
+    fn new(rows: usize) -> Self {
         AnalysisBox {
-            skill_values: [AnalysisFigure::Inactive; 6],
-            luck_values: [AnalysisFigure::Inactive; 6],
+            skill_values: vec![AnalysisFigure::Inactive; rows],
+            luck_values: vec![AnalysisFigure::Inactive; rows],
             is_displayed: true,
+            summary: None,
             //heuristic_table: HashMap::new()
         }
     }
-}
-impl AnalysisBox {
     fn view(&self) -> Element<'_, Message> {
         // let sv = 0.0;
         // let lv = 0.0;
         // row![container(text(format!("{:.2}        {1:.2}",sv,lv)))].into()
         if self.is_displayed {
-            Column::from_vec(
+            let header = row![
+                explained(text("Skill").size(20).color(color!(0x00ff00)), SKILL_TIP),
+                container(explained(text("Luck").size(20).color(color!(0xff0000)), LUCK_TIP))
+                    .padding(Padding {top: 0.0, right: 0.0, bottom: 0.0, left: 25.0})
+            ];
+            let mut rows = vec![header.into()];
+            rows.extend(
                 self.skill_values
                     .iter()
-                    .zip(self.luck_values)
-                    .map(|(&sv, lv)| {
-                        row![container(text(format!("{:.2}",sv)).size(20).color(color!(0x00ff00))),
-                            container(text(format!("{:.2}",lv)).size(20).color(color!(0xff0000)))
-                        .padding(Padding {top: 0.0, right: 0.0, bottom: 0.0, left: 40.0})]
+                    .zip(&self.luck_values)
+                    .map(|(&sv, &lv)| {
+                        row![container(text(format!("{}",sv)).size(20).color(color!(0x00ff00))).width(40),
+                            container(text(format!("{}",lv)).size(20).color(color!(0xff0000))).width(40)
+                        .padding(Padding {top: 0.0, right: 0.0, bottom: 0.0, left: 20.0})]
                             .padding(Padding {
                                 top: 40.0,
                                 right: 0.0,
@@ -457,143 +1160,624 @@ impl AnalysisBox {
                                 left: 0.0,
                             })
                             .into()
-                    })
-                    .collect(),
-            ).width(1000).align_x(Horizontal::Right)
+                    }),
+            );
+            if let Some((player, solver)) = &self.summary {
+                let mut lines = column![text(summary_line("You", player)).size(18).color(*WHITE)];
+                if let Some(solver) = solver {
+                    lines = lines.push(text(summary_line("Solver", solver)).size(18).color(*WHITE));
+                }
+                rows.push(container(lines).padding(Padding {top: 30.0, right: 0.0, bottom: 0.0, left: 0.0}).into());
+            }
+            Column::from_vec(rows).width(1000).align_x(Horizontal::Right)
                 .padding(Padding {
-                top: 80.0,
-                right: 220.0,
+                top: 60.0,
+                right: 200.0,
                 bottom: 0.0,
                 left: 0.0
             })
                 .into()
         } else {container(text("")).into()}
     }
-    fn update(&mut self, word: String, word_num: usize, skill: AnalysisFigure, info: f64) {
+    fn update(&mut self, word_num: usize, rating: Rating) {
         // if self.heuristic_table.contains_key(&word) {
         //     let heuristic_value = self.heuristic_table.get(&word).unwrap();
         //     self.skill_values[word_num] = AnalysisFigure::Active(*heuristic_value);
         // } else {
         //     self.skill_values[word_num] = AnalysisFigure::Unknown;
         // }
-        self.skill_values[word_num] = skill;
-        self.luck_values[word_num] = AnalysisFigure::Active(info);
+        self.skill_values[word_num] = rating.skill.map_or(AnalysisFigure::Unknown, AnalysisFigure::Active);
+        self.luck_values[word_num] = AnalysisFigure::Active(rating.luck);
+    }
+    /// The mean skill and luck of the game, for sharing; guesses outside
+    /// the list have no skill to count.
+    fn totals(&self) -> String {
+        let mean = |values: &[AnalysisFigure]| -> f64 {
+            let rated: Vec<f64> = values
+                .iter()
+                .filter_map(|figure| match figure {
+                    AnalysisFigure::Active(x) => Some(f64::from(*x)),
+                    _ => None,
+                })
+                .collect();
+            rated.iter().sum::<f64>() / rated.len().max(1) as f64
+        };
+        format!(
+            "Skill {:.0}, luck {:.0}",
+            mean(&self.skill_values),
+            mean(&self.luck_values)
+        )
     }
 }
 
-//#[derive(Default)]
+/// "You: 4 guesses, skill 82, luck 55", or "lost" in place of the guesses.
+fn summary_line(who: &str, summary: &GameSummary) -> String {
+    let guesses = match summary.guesses {
+        _ if !summary.won => "lost".to_string(),
+        1 => "1 guess".to_string(),
+        n => format!("{} guesses", n),
+    };
+    let skill = summary.skill.map_or("?".to_string(), |skill| format!("{:.0}", skill));
+    format!("{}: {}, skill {}, luck {:.0}", who, guesses, skill, summary.luck)
+}
+
+/// `content` with `tip` shown below it on hover.
+fn explained<'a>(content: impl Into<Element<'a, Message>>, tip: &'a str) -> Element<'a, Message> {
+    tooltip(
+        content,
+        container(text(tip).size(14).color(*WHITE)).padding(10).width(260).style(|theme| {
+            let mut x = container::rounded_box(theme);
+            x.background = Some((*DARK_GREY).into());
+            x
+        }),
+        tooltip::Position::Bottom,
+    )
+    .into()
+}
+
 struct Layout {
     title: Title,
-    entry_set: EntrySet,
+    /// One board per secret word; a single one unless playing Dordle and
+    /// the like.
+    entry_sets: Vec<EntrySet>,
+    /// Every word before any guess, so a new game needn't read the list again.
+    words: WordSet,
+    /// Guesses allowed on a single board; each extra board adds one.
+    max_guesses: usize,
     keyboard: Keyboard,
     suggestion_button: SuggestionButton,
     suggestion_box: SuggestionBox,
     analysis_button: AnalysisButton,
-    analysis_box: AnalysisBox
+    analysis_box: AnalysisBox,
+    strategy_picker: StrategyPicker,
+    board_picker: BoardPicker,
+    assist_button: AssistButton,
+    new_game_button: NewGameButton,
+    share_button: ShareButton,
+    review_button: ReviewButton,
+    review_box: ReviewBox,
+    free_form_button: FreeFormButton,
+    hard_mode_button: HardModeButton,
+    daily_button: DailyButton,
+    stats_button: StatsButton,
+    stats_box: StatsBox,
+    resume_prompt: ResumePrompt,
+    /// Where the game in progress is saved after every guess.
+    save_path: PathBuf,
+    /// The puzzle played while the daily button is on: today's, unless
+    /// another was asked for.
+    puzzle: u64,
+    toast: Toast,
+    /// When the active row started shaking, while it still is.
+    shake_start: Option<Instant>,
+    /// Horizontal offset of the active rows while they shake off a rejected
+    /// guess.
+    shake: f32,
+    /// Bumped on every new game, so analysis of a guess from the last one is
+    /// not drawn onto the fresh board.
+    game_number: u64,
+    /// Bumped every time a suggestion is requested, so a result that arrives
+    /// after the board has moved on can be recognised and dropped.
+    solver_turn: u64,
+    /// The suggestion being worked out: dropping the handle forgets the
+    /// result, and the flag stops the thread working on it.
+    solver: Option<(task::Handle, Arc<AtomicBool>)>,
 }
-impl Default for Layout {
-    fn default() -> Self {
+impl Layout {
+    /// Starts on the puzzle asked for, if any, and on a random word
+    /// otherwise.
+    fn new(words: WordSet, history: History, saved: Option<SavedGame>, settings: Settings) -> (Self, Task<Message>) {
+        let Settings {
+            max_guesses,
+            puzzle,
+            stats,
+            save,
+            ..
+        } = settings;
         let mut ans = Layout {
             title: Title::default(),
-            entry_set: EntrySet::default(),
-            keyboard: Keyboard::default(),
+            entry_sets: Vec::new(),
+            words,
+            max_guesses,
+            keyboard: Keyboard::new(1),
             suggestion_button: SuggestionButton::default(),
             suggestion_box: SuggestionBox::default(),
             analysis_button: AnalysisButton::default(),
-            analysis_box: AnalysisBox::default()
+            analysis_box: AnalysisBox::new(max_guesses),
+            strategy_picker: StrategyPicker::default(),
+            board_picker: BoardPicker::default(),
+            assist_button: AssistButton::default(),
+            new_game_button: NewGameButton,
+            share_button: ShareButton,
+            review_button: ReviewButton::default(),
+            review_box: ReviewBox::default(),
+            free_form_button: FreeFormButton::default(),
+            hard_mode_button: HardModeButton::default(),
+            daily_button: DailyButton::default(),
+            stats_button: StatsButton::default(),
+            stats_box: StatsBox {
+                history,
+                path: stats,
+                max_guesses,
+                is_displayed: false,
+            },
+            resume_prompt: ResumePrompt { saved },
+            save_path: save,
+            puzzle: puzzle.unwrap_or_else(todays_puzzle),
+            toast: Toast::default(),
+            shake_start: None,
+            shake: 0.0,
+            game_number: 0,
+            solver_turn: 0,
+            solver: None,
         };
-        // (ans.suggestion_box.suggestion, ans.analysis_box.heuristic_table) = ans
-        //     .entry_set
-        //     .suggestion_word_bank
-        //     .suggest();
-        ans.suggestion_box.suggestion = "tares".to_ascii_uppercase();
-        ans.suggestion_box.set_box(ans.suggestion_button.setting);
-        ans
+        if puzzle.is_some() {
+            ans.daily_button.toggle();
+        }
+        let task = ans.restart();
+        (ans, task)
     }
-}
-
-impl Layout {
     fn view(&self) -> Element<'_, Message> {
         stack![
             column![
                 self.title.view(),
-                self.entry_set.view(),
+                self.boards_view(),
                 self.keyboard.view()
             ],
-            column![self.suggestion_button.view(), self.analysis_button.view()].padding(Padding {
+            column![
+                self.suggestion_button.view(),
+                self.analysis_button.view(),
+                self.strategy_picker.view(),
+                self.board_picker.view(),
+                self.assist_button.view(),
+                self.free_form_button.view(),
+                self.hard_mode_button.view(),
+                self.daily_button.view(),
+                self.stats_button.view(),
+                self.new_game_button.view(),
+                self.share_button.view(self.status() != GameStatus::InProgress),
+                self.review_button.view(self.entry_sets.len() == 1 && self.status() != GameStatus::InProgress)
+            ]
+            .padding(Padding {
                 top: 20.0,
                 bottom: 0.0,
                 right: 0.0,
                 left: 0.0
             }),
             self.suggestion_box.view(),
-            self.analysis_box.view()
+            if self.entry_sets.len() == 1 {
+                self.analysis_box.view()
+            } else {
+                // Skill and luck are per board, with no room to show them.
+                container(text("")).into()
+            },
+            self.stats_box.view(),
+            self.review_box.view(),
+            self.resume_prompt.view(),
+            self.toast.view()
         ]
         .into()
     }
-    fn grade(&mut self, word: [char; 5]) -> [(char, Color); 5] {
-        let mut ans = [('A', *GREY); 5];
-        for i in 0..5 {
-            ans[i].0 = word[i];
-            if word[i] == self.entry_set.secret_word.chars().nth(i).unwrap() {
-                ans[i].1 = *GREEN;
-            } else if self.entry_set.secret_word.contains(word[i]) {
-                ans[i].1 = *YELLOW;
+    /// The boards in rows of up to four, shrunk to fit.
+    fn boards_view(&self) -> Element<'_, Message> {
+        let size = tile_size(self.entry_sets.len(), self.words.word_length(), self.rows());
+        let grid = Column::from_vec(
+            self.entry_sets
+                .chunks(BOARDS_PER_ROW)
+                .map(|boards| {
+                    Row::from_vec(boards.iter().map(|board| board.view(size, self.shake)).collect())
+                        .spacing(BOARD_SPACING)
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(BOARD_SPACING);
+        container(grid)
+            .center_x(1000)
+            .padding(Padding {
+                top: 20.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 0.0,
+            })
+            .into()
+    }
+    fn rows(&self) -> usize {
+        self.max_guesses + self.board_picker.selected.0 - 1
+    }
+    /// The boards still being played.
+    fn unsolved(&self) -> impl Iterator<Item = &EntrySet> {
+        self.entry_sets.iter().filter(|board| board.game.status() != GameStatus::Won)
+    }
+    /// Won once every board is solved, lost once the guesses run out.
+    fn status(&self) -> GameStatus {
+        let statuses: Vec<GameStatus> = self.entry_sets.iter().map(|board| board.game.status()).collect();
+        if statuses.contains(&GameStatus::Lost) {
+            GameStatus::Lost
+        } else if statuses.iter().all(|&status| status == GameStatus::Won) {
+            GameStatus::Won
+        } else {
+            GameStatus::InProgress
+        }
+    }
+    /// The row being typed into, which every unsolved board shares.
+    fn typed(&self) -> Option<&Entry> {
+        self.unsolved().next().map(|board| &board.entries[board.active_entry])
+    }
+    /// Starts computing the next suggestion, cancelling any still running
+    /// for an earlier turn.
+    fn suggest(&mut self) -> Task<Message> {
+        if let Some((handle, cancel)) = self.solver.take() {
+            cancel.store(true, Ordering::Relaxed);
+            handle.abort();
+        }
+        self.solver_turn += 1;
+        let turn = self.solver_turn;
+        self.suggestion_box.is_thinking = true;
+        self.suggestion_box.set_box(self.suggestion_button.setting);
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let boards: Vec<WordSet> = self
+            .unsolved()
+            .map(|board| {
+                let mut word_set = board.game.word_set().clone();
+                word_set.set_cancel(cancel.clone());
+                word_set
+            })
+            .collect();
+        // A single board follows the chosen strategy; several are ranked by
+        // the information a guess gives on all of them together.
+        let (task, handle) = Task::perform(
+            in_background(move || match &boards[..] {
+                [word_set] => word_set.rank(SUGGESTION_COUNT),
+                boards => rank_boards(boards, SUGGESTION_COUNT),
+            }),
+            move |ranking| Message::SuggestionReady(turn, ranking),
+        )
+        .abortable();
+        self.solver = Some((handle, cancel));
+        task
+    }
+    /// Starts a new game in the current mode, with fresh secrets unless
+    /// assisting.
+    fn restart(&mut self) -> Task<Message> {
+        // The save is kept while it's still on offer.
+        if self.resume_prompt.saved.is_none() {
+            self.forget_game();
+        }
+        let boards = self.board_picker.selected.0;
+        let games: Vec<Game> = if self.assist_button.setting {
+            vec![Game::assist(self.words.clone())]
+        } else {
+            let secrets = if self.daily_button.setting {
+                let answers: Vec<Guess> = self.words.answer_words().collect();
+                daily_answers(&answers, self.puzzle, boards)
             } else {
-                ans[i].1 = *DARK_GREY;
+                self.words.answer_words().choose_multiple(&mut rand::thread_rng(), boards)
+            };
+            secrets.into_iter().map(|secret| Game::new(secret, self.words.clone())).collect()
+        };
+        let rows = self.rows();
+        let games = games
+            .into_iter()
+            .map(|mut game| {
+                game.set_max_guesses(rows);
+                game.set_hard_mode(self.hard_mode_button.setting);
+                game
+            })
+            .collect();
+        self.start(games)
+    }
+    /// Puts `games` on the boards, however far they have got.
+    fn start(&mut self, games: Vec<Game>) -> Task<Message> {
+        self.game_number += 1;
+        self.entry_sets = games
+            .into_iter()
+            .map(|mut game| {
+                game.set_strategy(self.strategy_picker.selected.build());
+                EntrySet::new(game)
+            })
+            .collect();
+        self.keyboard = Keyboard::new(self.entry_sets.len());
+        self.review_box = ReviewBox::default();
+        if self.review_button.setting {
+            self.review_button.toggle();
+        }
+        for (i, board) in self.entry_sets.iter().enumerate() {
+            for turn in board.game.turns() {
+                self.keyboard.grade(i, &turn.guess, &turn.pattern);
+            }
+        }
+        self.analysis_box = AnalysisBox {
+            is_displayed: self.analysis_box.is_displayed,
+            ..AnalysisBox::new(self.rows())
+        };
+        self.title = Title::default();
+        if self.daily_button.setting && !self.assist_button.setting {
+            self.title.text = format!("Wordle #{}", self.puzzle);
+        }
+        self.suggestion_box.ranking.clear();
+        let mut tasks = vec![self.suggest()];
+        if let [board] = &self.entry_sets[..] {
+            // Rated as they were played, against only the guesses hard mode
+            // allowed.
+            let mut bank = self.words.clone();
+            bank.set_hard_mode(board.game.word_set().hard_mode().is_some());
+            for (row, &turn) in board.game.turns().iter().enumerate() {
+                tasks.push(analyse(self.game_number, row, bank.clone(), turn));
+                bank.reduce(&turn.guess, &turn.pattern);
             }
         }
-        ans
+        Task::batch(tasks)
     }
-    fn update(&mut self, message: Message) {
-        match message {
-            Message::EnterText(_c) => {
-                self.entry_set.entries[self.entry_set.active_entry].update(message);
+    /// Picks up the game saved last time, with the settings it was played
+    /// under.
+    fn resume(&mut self, saved: SavedGame) -> Task<Message> {
+        let games = match saved.restore(&self.words, MAX_ROWS) {
+            Ok(games) => games,
+            Err(reason) => {
+                eprintln!("Could not resume the game: {}", reason);
+                self.toast.show("Could not resume the game", Instant::now());
+                return Task::batch([self.restart(), self.fit_window()]);
             }
-            Message::DeleteText => {
-                self.entry_set.entries[self.entry_set.active_entry].update(message);
+        };
+        let boards = games.len();
+        self.board_picker.selected = BoardCount(boards);
+        self.max_guesses = (saved.max_guesses + 1).saturating_sub(boards).max(1);
+        if self.assist_button.setting != games[0].secret().is_none() {
+            self.assist_button.toggle();
+        }
+        if self.hard_mode_button.setting != saved.hard_mode {
+            self.hard_mode_button.toggle();
+        }
+        if self.daily_button.setting != saved.puzzle.is_some() {
+            self.daily_button.toggle();
+        }
+        if let Some(puzzle) = saved.puzzle {
+            self.puzzle = puzzle;
+        }
+        Task::batch([self.start(games), self.fit_window()])
+    }
+    /// Saves the game while it is in progress, and drops the save once it
+    /// is over.
+    fn save_game(&mut self) {
+        if self.status() != GameStatus::InProgress {
+            self.forget_game();
+            return;
+        }
+        let games: Vec<Game> = self.entry_sets.iter().map(|board| board.game.clone()).collect();
+        let puzzle = self.daily_button.setting.then_some(self.puzzle);
+        if let Err(e) = SavedGame::new(&games, puzzle).save(&self.save_path) {
+            eprintln!("Could not save the game: {}", e);
+            self.toast.show("Could not save the game", Instant::now());
+        }
+    }
+    fn forget_game(&mut self) {
+        if let Err(e) = SavedGame::remove(&self.save_path) {
+            eprintln!("Could not remove the saved game: {}", e);
+        }
+    }
+    /// Resizes the window to fit the boards.
+    fn fit_window(&self) -> Task<Message> {
+        let height = window_height(self.entry_sets.len(), self.words.word_length(), self.rows());
+        window::get_latest().and_then(move |id| window::resize(id, Size::new(1000.0, height)))
+    }
+    /// Turns a guess away without using up the row.
+    fn reject(&mut self, reason: &str) {
+        let now = Instant::now();
+        self.toast.show(reason, now);
+        self.shake_start = Some(now);
+    }
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::EnterText(_) | Message::DeleteText | Message::Enter if self.resume_prompt.saved.is_some() => {}
+            Message::EnterText(_) | Message::DeleteText => {
+                if self.status() == GameStatus::InProgress {
+                    let playing = self.entry_sets.iter_mut().filter(|board| board.game.status() == GameStatus::InProgress);
+                    for board in playing {
+                        board.entries[board.active_entry].update(message.clone());
+                    }
+                }
             }
             Message::Enter => {
-                if !self.entry_set.entries[self.entry_set.active_entry]
-                    .chars
-                    .contains(&' ')
+                if self.status() == GameStatus::InProgress
+                    && let Some(entry) = self.typed()
+                    && !entry.chars.contains(&' ')
                 {
-                    let word: String = self.entry_set.entries[self.entry_set.active_entry].chars.iter().collect();
-                    let exp_info = self.entry_set.suggestion_word_bank.test_entry(word.to_ascii_lowercase());
-                    let old_bank_length = self.entry_set.suggestion_word_bank.answer_words.len();
-                    
-                    let grade_result =
-                        self.grade(self.entry_set.entries[self.entry_set.active_entry].chars);
-                    self.entry_set.grade(grade_result);
-                    self.keyboard.grade(grade_result);
-                    
-                    self.entry_set.suggestion_word_bank.reduce(grade_result);
-                    let new_bank_length = self.entry_set.suggestion_word_bank.answer_words.len();
-                    let info = ((old_bank_length as f64) / (new_bank_length as f64)).log(2.0);
-                    println!("Actual Info: {:.2}", info);
-                    
-                    let word: String = grade_result.iter().map(|(c, Color)| c).collect();
-                    self.analysis_box.update(word.clone(), self.entry_set.active_entry, exp_info, info);
-                    self.suggestion_box.suggestion = self
-                        .entry_set
-                        .suggestion_word_bank
-                        .suggest();
-                    self.suggestion_box.set_box(self.suggestion_button.setting);
-                    if grade_result.iter().all(|(_, color)| *color == *GREEN) {
-                        self.update(Message::GameOver(GameResult::WIN));
-                    } else if self.entry_set.active_entry == 5 {
-                        self.update(Message::GameOver(GameResult::LOSE));
-                    } else {
-                        self.entry_set.active_entry += 1;
-                        self.entry_set.entries[self.entry_set.active_entry].colors = [*GREY; 5];
+                    let guess = Guess::new(&entry.chars);
+                    let pattern = entry.pattern();
+                    if !self.free_form_button.setting && !self.words.is_allowed(&guess) {
+                        self.reject("Not in word list");
+                        return Task::none();
+                    }
+                    let illegal = self.unsolved().find_map(|board| board.game.check_hard_mode(&guess).err());
+                    if let Some(reason) = illegal {
+                        self.reject(&reason);
+                        return Task::none();
                     }
+                    // In assist the colours are typed in, and a mistake can leave
+                    // no answer that fits them.
+                    let contradicted = self.unsolved().any(|board| {
+                        board.is_assist()
+                            && !board.game.word_set().answer_words().any(|answer| Pattern::score(&guess, &answer) == pattern)
+                    });
+                    if contradicted {
+                        self.reject("No answers fit those colours");
+                        return Task::none();
+                    }
+
+                    let mut tasks = Vec::new();
+                    let single = self.entry_sets.len() == 1;
+                    let game_number = self.game_number;
+                    for (i, board) in self.entry_sets.iter_mut().enumerate() {
+                        if board.game.status() != GameStatus::InProgress {
+                            continue;
+                        }
+                        let old_bank = board.game.word_set().clone();
+                        let turn = if board.is_assist() {
+                            board.game.record(guess, pattern)
+                        } else {
+                            board.game.play(guess)
+                        };
+                        board.grade(&turn.pattern);
+                        self.keyboard.grade(i, &guess, &turn.pattern);
+                        let row = board.active_entry;
+                        board.advance();
+                        if single {
+                            tasks.push(analyse(game_number, row, old_bank, turn));
+                        }
+                    }
+                    self.save_game();
+                    tasks.push(self.suggest());
+                    match self.status() {
+                        GameStatus::Won => tasks.push(self.update(Message::GameOver(GameResult::Win))),
+                        GameStatus::Lost => tasks.push(self.update(Message::GameOver(GameResult::Lose))),
+                        GameStatus::InProgress => {}
+                    }
+                    return Task::batch(tasks);
                 }
             }
             Message::GameOver(result) => {
-                self.title.text = format!("Answer: {}", self.entry_set.secret_word);
+                let secrets: Vec<String> = self
+                    .entry_sets
+                    .iter()
+                    .filter_map(|board| board.game.secret())
+                    .map(|secret| secret.to_string().to_ascii_uppercase())
+                    .collect();
+                self.title.text = match result {
+                    _ if !secrets.is_empty() => format!("Answer: {}", secrets.join(" ")),
+                    GameResult::Win => "Solved!".to_string(),
+                    GameResult::Lose => "Out of guesses".to_string(),
+                };
                 match result {
-                    GameResult::WIN => self.title.color = *GREEN,
-                    GameResult::LOSE => self.title.color = *RED,
+                    GameResult::Win => self.title.color = *GREEN,
+                    GameResult::Lose => self.title.color = *RED,
+                }
+                let [board] = &self.entry_sets[..] else {
+                    return Task::none();
+                };
+                // The player is summarised beside the solver playing the same
+                // secret with the same strategy.
+                let game = board.game.clone();
+                let mut start = self.words.clone();
+                start.set_strategy(self.strategy_picker.selected.build());
+                start.set_hard_mode(game.word_set().hard_mode().is_some());
+                let game_number = self.game_number;
+                // Only single games against a secret count towards the
+                // statistics, with the ratings the summary is made from.
+                let puzzle = self.daily_button.setting.then_some(self.puzzle);
+                let date = Local::now().date_naive();
+                return Task::future(in_background(move || {
+                    let ratings = rate_game(&game, &start);
+                    let player = GameSummary::new(&game, &ratings);
+                    let solver = game.secret().map(|secret| {
+                        let solver = solver_game(&start, secret, game.max_guesses());
+                        GameSummary::new(&solver, &rate_game(&solver, &start))
+                    });
+                    (player, solver, GameRecord::new(&game, ratings, date, puzzle))
+                }))
+                .then(move |(player, solver, record)| {
+                    let summary = Task::done(Message::SummaryReady(game_number, player, solver));
+                    match record {
+                        Some(record) => Task::batch([summary, Task::done(Message::GameRecorded(Box::new(record)))]),
+                        None => summary,
+                    }
+                });
+            }
+            Message::GameRecorded(record) => {
+                if let Err(e) = self.stats_box.record(*record) {
+                    eprintln!("Could not save the statistics: {}", e);
+                    self.toast.show("Could not save statistics", Instant::now());
+                }
+            }
+            Message::ResumeGame => {
+                if let Some(saved) = self.resume_prompt.saved.take() {
+                    return self.resume(saved);
+                }
+            }
+            Message::AbandonGame => {
+                self.resume_prompt.saved = None;
+                self.forget_game();
+            }
+            Message::Share => {
+                let games: Vec<Game> = self.entry_sets.iter().map(|board| board.game.clone()).collect();
+                let puzzle = self.daily_button.setting.then_some(self.puzzle);
+                let mut result = share_text(&games, puzzle);
+                // The figures go along only when the player has them on show.
+                if games.len() == 1 && self.analysis_button.setting {
+                    result += &format!("\n\n{}", self.analysis_box.totals());
+                }
+                self.toast.show("Copied results to clipboard", Instant::now());
+                return clipboard::write(result);
+            }
+            Message::ToggleReview => {
+                self.review_button.toggle();
+                self.review_box.is_displayed = self.review_button.setting;
+                // The replay runs the solver once a turn, so only once a game.
+                if self.review_box.is_displayed
+                    && self.review_box.turns.is_empty()
+                    && !self.review_box.is_thinking
+                    && let [board] = &self.entry_sets[..]
+                {
+                    self.review_box.is_thinking = true;
+                    let game = board.game.clone();
+                    let mut start = self.words.clone();
+                    start.set_strategy(self.strategy_picker.selected.build());
+                    start.set_hard_mode(game.word_set().hard_mode().is_some());
+                    let game_number = self.game_number;
+                    return Task::perform(in_background(move || review(&game, &start)), move |turns| {
+                        Message::ReviewReady(game_number, turns)
+                    });
+                }
+            }
+            Message::ReviewReady(game_number, turns) => {
+                if game_number == self.game_number {
+                    self.review_box.turns = turns;
+                    self.review_box.current = 0;
+                    self.review_box.is_thinking = false;
+                }
+            }
+            Message::ReviewTurn(i) => self.review_box.current = i,
+            Message::ToggleStats => {
+                self.stats_button.toggle();
+                self.stats_box.is_displayed = self.stats_button.setting;
+            }
+            Message::SuggestionReady(turn, ranking) => {
+                if turn == self.solver_turn {
+                    self.solver = None;
+                    self.suggestion_box.ranking = ranking;
+                    self.suggestion_box.is_thinking = false;
+                    self.suggestion_box.set_box(self.suggestion_button.setting);
+                }
+            }
+            Message::AnalysisReady(game_number, word_num, rating) => {
+                if game_number == self.game_number {
+                    self.analysis_box.update(word_num, rating);
+                }
+            }
+            Message::SummaryReady(game_number, player, solver) => {
+                if game_number == self.game_number {
+                    self.analysis_box.summary = Some((player, solver));
                 }
             }
             Message::ToggleSuggest => {
@@ -604,26 +1788,184 @@ impl Layout {
                 self.analysis_button.toggle();
                 self.analysis_box.is_displayed = self.analysis_button.setting;
             }
+            Message::SelectStrategy(kind) => {
+                if kind != self.strategy_picker.selected {
+                    self.strategy_picker.selected = kind;
+                    for board in &mut self.entry_sets {
+                        board.game.set_strategy(kind.build());
+                    }
+                    return self.suggest();
+                }
+            }
+            Message::ToggleAssist => {
+                self.assist_button.toggle();
+                // Feedback from elsewhere is entered for one board at a time.
+                if self.assist_button.setting && self.board_picker.selected != BoardCount(1) {
+                    self.board_picker.selected = BoardCount(1);
+                    return Task::batch([self.restart(), self.fit_window()]);
+                }
+                return self.restart();
+            }
+            Message::SelectBoards(count) => {
+                if count != self.board_picker.selected {
+                    self.board_picker.selected = count;
+                    if count != BoardCount(1) && self.assist_button.setting {
+                        self.assist_button.toggle();
+                    }
+                    return Task::batch([self.restart(), self.fit_window()]);
+                }
+            }
+            Message::NewGame => return self.restart(),
+            Message::ToggleDaily => {
+                self.daily_button.toggle();
+                return self.restart();
+            }
+            Message::ToggleFreeForm => self.free_form_button.toggle(),
+            Message::ToggleHardMode => {
+                self.hard_mode_button.toggle();
+                // Hints already revealed can't be held against the player, so
+                // the rule only changes between games.
+                if self.entry_sets.iter().all(|board| board.game.turns().is_empty()) {
+                    for board in &mut self.entry_sets {
+                        board.game.set_hard_mode(self.hard_mode_button.setting);
+                    }
+                    return self.suggest();
+                }
+                self.toast.show("Applies from the next game", Instant::now());
+            }
+            Message::Tick(now) => {
+                if let Some(shown_at) = self.toast.shown_at
+                    && now.duration_since(shown_at) >= TOAST_TIME
+                {
+                    self.toast = Toast::default();
+                }
+                self.shake = match self.shake_start {
+                    Some(start) if now.duration_since(start) < SHAKE_TIME => {
+                        let t = now.duration_since(start).as_secs_f32() / SHAKE_TIME.as_secs_f32();
+                        SHAKE_DISTANCE * (1.0 - t) * (t * 8.0 * std::f32::consts::PI).sin()
+                    }
+                    _ => {
+                        self.shake_start = None;
+                        0.0
+                    }
+                };
+            }
+            Message::CycleTile(i) => {
+                if let [board] = &mut self.entry_sets[..]
+                    && board.is_assist()
+                    && board.game.status() == GameStatus::InProgress
+                {
+                    board.entries[board.active_entry].cycle(i);
+                }
+            }
         }
+        Task::none()
     }
     fn subscription(&self) -> Subscription<Message> {
-        keyboard::on_key_press(parse_keyboard_input)
+        let keys = keyboard::on_key_press(parse_keyboard_input);
+        if self.toast.text.is_some() || self.shake_start.is_some() {
+            Subscription::batch([keys, window::frames().map(Message::Tick)])
+        } else {
+            keys
+        }
     }
 }
 
-fn letter_block(c: char, color: Color) -> Container<'static, Message> {
-    container(text(c.to_ascii_uppercase().to_string()).size(50))
-        .center(55)
+/// Runs `work` on its own thread and resolves with its result, so solver
+/// work never stalls the iced executor.
+fn in_background<T: Send + 'static>(
+    work: impl FnOnce() -> T + Send + 'static,
+) -> impl Future<Output = T> {
+    let (sender, receiver) = oneshot::channel();
+    thread::spawn(move || {
+        let _ = sender.send(work());
+    });
+    async move { receiver.await.expect("solver thread panicked") }
+}
+
+const BOARDS_PER_ROW: usize = 4;
+const BOARD_SPACING: f32 = 20.0;
+/// Room for the boards beside the buttons and suggestions, and below the
+/// title on a screen of ordinary height.
+const BOARDS_WIDTH: f32 = 600.0;
+const BOARDS_HEIGHT: f32 = 620.0;
+
+/// Rates a guess against the candidates it was played into, off the UI
+/// thread. Skill needs every possible guess scored against those candidates.
+fn analyse(game_number: u64, word_num: usize, old_bank: WordSet, turn: Turn) -> Task<Message> {
+    Task::perform(
+        in_background(move || rate(&old_bank, &turn.guess, &turn.pattern)),
+        move |rating| Message::AnalysisReady(game_number, word_num, rating),
+    )
+}
+
+/// Size of a letter tile, shrunk so that several boards fit side by side.
+fn tile_size(boards: usize, length: usize, rows: usize) -> f32 {
+    if boards == 1 {
+        return 55.0;
+    }
+    let columns = boards.min(BOARDS_PER_ROW);
+    let grid_rows = boards.div_ceil(BOARDS_PER_ROW);
+    let margins = BOARD_SPACING * (columns - 1) as f32 + 2.0 * SHAKE_DISTANCE * columns as f32;
+    let by_width = (BOARDS_WIDTH - margins) / (columns * length) as f32 - 5.0;
+    let by_height = (BOARDS_HEIGHT - BOARD_SPACING * (grid_rows - 1) as f32) / (grid_rows * rows) as f32;
+    by_width.min(by_height * 0.85).min(55.0).floor()
+}
+
+/// Gap between the rows of a board with tiles of `size`.
+fn row_spacing(size: f32) -> f32 {
+    (size / 5.5).round()
+}
+
+/// Window height that fits the title, the boards and the keyboard.
+fn window_height(boards: usize, length: usize, rows: usize) -> f32 {
+    let size = tile_size(boards, length, rows);
+    let grid_rows = boards.div_ceil(BOARDS_PER_ROW);
+    let board = rows as f32 * (size + row_spacing(size));
+    378.0 + grid_rows as f32 * board + (grid_rows - 1) as f32 * BOARD_SPACING
+}
+
+fn letter_block(c: char, color: Color, size: f32) -> Container<'static, Message> {
+    container(text(c.to_ascii_uppercase().to_string()).size(size * 50.0 / 55.0))
+        .center(size)
         .style(move |theme| {
             let mut x = container::rounded_box(theme);
             x.background = Some(color.into());
             x
         })
-        .into()
 }
 
-fn parse_keyboard_input(key: Key, _modifiers: Modifiers) -> Option<Message> {
+/// A keyboard key, striped with its colour on each board.
+fn key_block(c: char, colors: Vec<Color>) -> Element<'static, Message> {
+    let width = 55.0 / colors.len() as f32;
+    let stripes = Row::from_vec(
+        colors
+            .into_iter()
+            .map(|color| {
+                container(text(""))
+                    .width(width)
+                    .height(55)
+                    .style(move |_| container::Style {
+                        background: Some(color.into()),
+                        ..container::Style::default()
+                    })
+                    .into()
+            })
+            .collect(),
+    );
+    stack![
+        container(stripes).clip(true).style(container::rounded_box),
+        container(text(c.to_ascii_uppercase().to_string()).size(50)).center(55)
+    ]
+    .into()
+}
+
+fn parse_keyboard_input(key: Key, modifiers: Modifiers) -> Option<Message> {
     match key {
+        Key::Character(c) if modifiers.command() => match c.as_str() {
+            "n" | "N" => Some(Message::NewGame),
+            _ => None,
+        },
         Key::Character(c) => Some(Message::EnterText(c.to_ascii_uppercase().parse().unwrap())),
         Key::Named(Named::Backspace) => Some(Message::DeleteText),
         Key::Named(Named::Enter) => Some(Message::Enter),
@@ -631,9 +1973,91 @@ fn parse_keyboard_input(key: Key, _modifiers: Modifiers) -> Option<Message> {
     }
 }
 
+const USAGE: &str = "usage: wordle [--words FILE] [--guesses FILE] [--length N] [--max-guesses N] \
+                     [--daily | --seed N] [--stats FILE] [--save FILE]";
+
+/// What to play, from the command line.
+struct Settings {
+    words: PathBuf,
+    guesses: PathBuf,
+    length: usize,
+    max_guesses: usize,
+    /// The puzzle to start on: today's with `--daily`, or any with `--seed`.
+    puzzle: Option<u64>,
+    /// Where finished games are recorded.
+    stats: PathBuf,
+    /// Where the game in progress is kept between sessions.
+    save: PathBuf,
+}
+
+impl Settings {
+    fn from_args() -> Result<Settings, String> {
+        let mut settings = Settings {
+            words: PathBuf::from("sgb-words-trimmed.txt"),
+            guesses: PathBuf::from("sgb-words.txt"),
+            length: Guess::DEFAULT_LEN,
+            max_guesses: Game::MAX_GUESSES,
+            puzzle: None,
+            stats: PathBuf::from("wordle-stats.jsonl"),
+            save: PathBuf::from("wordle-save.json"),
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--daily" {
+                settings.puzzle = Some(todays_puzzle());
+                continue;
+            }
+            let value = args.next().ok_or(USAGE)?;
+            let number = || value.parse::<usize>().map_err(|_| format!("{} needs a number", arg));
+            match arg.as_str() {
+                "--words" => settings.words = PathBuf::from(&value),
+                "--guesses" => settings.guesses = PathBuf::from(&value),
+                "--stats" => settings.stats = PathBuf::from(&value),
+                "--save" => settings.save = PathBuf::from(&value),
+                "--length" => settings.length = number()?,
+                "--max-guesses" => settings.max_guesses = number()?,
+                "--seed" => settings.puzzle = Some(number()? as u64),
+                _ => return Err(USAGE.to_string()),
+            }
+        }
+        if !(Guess::MIN_LEN..=Guess::MAX_LEN).contains(&settings.length) {
+            return Err(format!("--length must be {} to {}", Guess::MIN_LEN, Guess::MAX_LEN));
+        }
+        if !(1..=MAX_ROWS).contains(&settings.max_guesses) {
+            return Err(format!("--max-guesses must be 1 to {}", MAX_ROWS));
+        }
+        Ok(settings)
+    }
+}
+
+/// The most rows that fit on a screen.
+const MAX_ROWS: usize = 10;
+
 fn main() -> iced::Result {
     //let entry = Entry::from_strings(['h','e','l','l','o']);
+    let settings = Settings::from_args().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(2);
+    });
+    let words = WordSet::load_lists(&settings.words, &settings.guesses, settings.length).unwrap_or_else(|e| {
+        eprintln!("Could not load the word lists: {}", e);
+        process::exit(1);
+    });
+    // Statistics or a save that can't be read are no reason not to play.
+    let history = History::load(&settings.stats).unwrap_or_else(|e| {
+        eprintln!("Could not load the statistics: {}", e);
+        History::default()
+    });
+    for skipped in history.skipped() {
+        eprintln!("Skipped a game in the statistics: {}", skipped);
+    }
+    let saved = SavedGame::load(&settings.save).unwrap_or_else(|e| {
+        eprintln!("Could not load the saved game: {}", e);
+        None
+    });
+    let height = window_height(1, settings.length, settings.max_guesses);
     iced::application("Wordle in Rust!", Layout::update, Layout::view)
         .subscription(Layout::subscription)
-        .run()
+        .window_size((1000.0, height))
+        .run_with(move || Layout::new(words, history, saved, settings))
 }
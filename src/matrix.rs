@@ -0,0 +1,156 @@
+use crate::feedback::{Guess, Pattern};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CACHE_MAGIC: [u8; 4] = *b"WDLP";
+/// Bump whenever the file layout or the scoring rules change, so stale
+/// caches are rebuilt instead of trusted.
+const CACHE_VERSION: u32 = 2;
+const HEADER_LEN: usize = 4 + 4 + 8 + 4 + 4;
+
+/// The pattern id of every guess scored against every answer.
+///
+/// Building it is the expensive part of the solver, so it is done once per
+/// pair of word lists and cached on disk by [`PatternMatrix::load_or_build`].
+/// Every word in both lists has the same length.
+pub struct PatternMatrix {
+    guesses: Vec<Guess>,
+    answers: Vec<Guess>,
+    guess_index: HashMap<Guess, usize>,
+    patterns: Vec<u16>,
+}
+
+impl PatternMatrix {
+    pub fn new(guesses: Vec<Guess>, answers: Vec<Guess>) -> PatternMatrix {
+        let mut patterns = Vec::with_capacity(guesses.len() * answers.len());
+        for guess in &guesses {
+            patterns.extend(answers.iter().map(|answer| Pattern::score(guess, answer).id()));
+        }
+        PatternMatrix::from_parts(guesses, answers, patterns)
+    }
+    /// Loads the matrix cached at `path`, rebuilding and rewriting the cache
+    /// if it is missing, unreadable or was built from other lists.
+    pub fn load_or_build(path: &Path, guesses: Vec<Guess>, answers: Vec<Guess>) -> PatternMatrix {
+        let key = list_hash(&guesses, &answers);
+        if let Ok(patterns) = read_cache(path, key, guesses.len(), answers.len()) {
+            return PatternMatrix::from_parts(guesses, answers, patterns);
+        }
+        let matrix = PatternMatrix::new(guesses, answers);
+        if let Err(e) = write_cache(path, key, &matrix) {
+            eprintln!("Could not write pattern cache {}: {}", path.display(), e);
+        }
+        matrix
+    }
+    /// Where the matrix for a pair of lists is cached, next to the answers:
+    /// `sgb-words.txt` on its own as `sgb-words.patterns`, and with guesses
+    /// from `all.txt` as `sgb-words+all.patterns`. Words of other than five
+    /// letters get their own cache, such as `sgb-words.6.patterns`.
+    pub fn cache_path(answer_list: &Path, guess_list: &Path, length: usize) -> PathBuf {
+        let stem = |path: &Path| path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        let mut name = stem(answer_list);
+        if answer_list != guess_list {
+            name = format!("{}+{}", name, stem(guess_list));
+        }
+        if length != Guess::DEFAULT_LEN {
+            name = format!("{}.{}", name, length);
+        }
+        answer_list.with_file_name(format!("{}.patterns", name))
+    }
+    fn from_parts(guesses: Vec<Guess>, answers: Vec<Guess>, patterns: Vec<u16>) -> PatternMatrix {
+        let guess_index = guesses.iter().enumerate().map(|(i, &guess)| (guess, i)).collect();
+        PatternMatrix {
+            guesses,
+            answers,
+            guess_index,
+            patterns,
+        }
+    }
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+    pub fn answers(&self) -> &[Guess] {
+        &self.answers
+    }
+    /// Letters in every word, or the default if there are no words at all.
+    pub fn length(&self) -> usize {
+        self.answers.first().or(self.guesses.first()).map_or(Guess::DEFAULT_LEN, Guess::length)
+    }
+    pub fn guess_index(&self, guess: &Guess) -> Option<usize> {
+        self.guess_index.get(guess).copied()
+    }
+    /// Pattern ids of one guess against every answer, in answer order.
+    pub fn row(&self, guess: usize) -> &[u16] {
+        let width = self.answers.len();
+        &self.patterns[guess * width..(guess + 1) * width]
+    }
+    pub fn pattern(&self, guess: usize, answer: usize) -> u16 {
+        self.patterns[guess * self.answers.len() + answer]
+    }
+}
+
+/// FNV-1a over both lists. Unlike `DefaultHasher` it is stable across
+/// compiler versions, which matters for a key that is written to disk.
+fn list_hash(guesses: &[Guess], answers: &[Guess]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for list in [guesses, answers] {
+        // Words are separated, so lists of different lengths never collide.
+        for c in list.iter().flat_map(|guess| guess.chars().iter().copied().chain([' '])).chain(['\n']) {
+            hash ^= c as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+fn read_cache(path: &Path, key: u64, guesses: usize, answers: usize) -> io::Result<Vec<u16>> {
+    let bytes = fs::read(path)?;
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "stale pattern cache");
+    if bytes.len() != HEADER_LEN + 2 * guesses * answers {
+        return Err(invalid());
+    }
+    let (header, patterns) = bytes.split_at(HEADER_LEN);
+    let mut expected = Vec::with_capacity(HEADER_LEN);
+    write_header(&mut expected, key, guesses, answers);
+    if header != expected {
+        return Err(invalid());
+    }
+    Ok(patterns.as_chunks::<2>().0.iter().map(|&id| u16::from_le_bytes(id)).collect())
+}
+
+fn write_cache(path: &Path, key: u64, matrix: &PatternMatrix) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + 2 * matrix.patterns.len());
+    write_header(&mut bytes, key, matrix.guesses.len(), matrix.answers.len());
+    bytes.extend(matrix.patterns.iter().flat_map(|id| id.to_le_bytes()));
+    fs::write(path, bytes)
+}
+
+fn write_header(bytes: &mut Vec<u8>, key: u64, guesses: usize, answers: usize) {
+    bytes.extend_from_slice(&CACHE_MAGIC);
+    bytes.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&key.to_le_bytes());
+    bytes.extend_from_slice(&(guesses as u32).to_le_bytes());
+    bytes.extend_from_slice(&(answers as u32).to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::guesses;
+
+    #[test]
+    fn cache_round_trip_and_invalidation() {
+        let list = std::env::temp_dir().join(format!("wordle-matrix-{}.patterns", std::process::id()));
+        let words = guesses(&["crane", "speed", "llama"]);
+        let built = PatternMatrix::load_or_build(&list, words.clone(), words.clone());
+        let loaded = PatternMatrix::load_or_build(&list, words.clone(), words.clone());
+        assert_eq!(built.patterns, loaded.patterns);
+        assert_eq!(loaded.pattern(1, 2), Pattern::score(&words[1], &words[2]).id());
+
+        let other = guesses(&["crane", "speed", "eerie"]);
+        let rebuilt = PatternMatrix::load_or_build(&list, other.clone(), other.clone());
+        assert_eq!(rebuilt.pattern(1, 2), Pattern::score(&other[1], &other[2]).id());
+        fs::remove_file(&list).unwrap();
+    }
+}
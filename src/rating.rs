@@ -0,0 +1,146 @@
+use crate::feedback::{Guess, Pattern};
+use crate::game::{Game, GameStatus};
+use crate::suggestion::WordSet;
+use serde::{Deserialize, Serialize};
+
+/// How well a guess was chosen, and how kindly the reply fell, both out of
+/// 99.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rating {
+    /// Expected information of the guess against the best one available:
+    /// 99 for the best, 0 for a guess that can't tell any answers apart.
+    /// `None` for a guess outside the list.
+    pub skill: Option<u8>,
+    /// Mid-rank percentile of the reply among every reply the guess could
+    /// have got, weighted by how many answers give each: the answers whose
+    /// reply would have left more, plus half of those whose reply leaves as
+    /// many, as a share of all of them, capped at 99. The reply leaving the
+    /// fewest answers scores high, but 99 only when nearly every answer would
+    /// have given another reply; 50 is ordinary.
+    pub luck: u8,
+}
+
+/// Rates playing `guess` into `set` and getting `pattern` back.
+pub fn rate(set: &WordSet, guess: &Guess, pattern: &Pattern) -> Rating {
+    Rating {
+        skill: skill(set, guess),
+        luck: luck(set, guess, pattern),
+    }
+}
+
+fn skill(set: &WordSet, guess: &Guess) -> Option<u8> {
+    let played = set.guess_stats(guess)?;
+    // With one answer left only that answer is worth playing, though no
+    // guess gains any information.
+    if set.answer_count() <= 1 {
+        return Some(if played.could_be_answer { 99 } else { 0 });
+    }
+    let best = set
+        .all_stats()
+        .into_iter()
+        .map(|(_, stats)| stats.expected_info)
+        .fold(played.expected_info, f64::max);
+    Some((99.0 * played.expected_info / best).round() as u8)
+}
+
+fn luck(set: &WordSet, guess: &Guess, pattern: &Pattern) -> u8 {
+    // With no answers left no reply fits, so none is lucky.
+    if set.answer_count() == 0 {
+        return 0;
+    }
+    // Scored directly, so guesses outside the list have a luck too.
+    let mut buckets = vec![0usize; Pattern::count(set.word_length())];
+    for answer in set.answer_words() {
+        buckets[Pattern::score(guess, &answer).id() as usize] += 1;
+    }
+    let left = buckets[pattern.id() as usize];
+    let total = set.answer_count() as f64;
+    let worse: usize = buckets.iter().filter(|&&count| count > left).sum();
+    let tied: usize = buckets.iter().filter(|&&count| count == left).sum();
+    let percentile = 100.0 * (worse as f64 + tied as f64 / 2.0) / total;
+    percentile.min(99.0) as u8
+}
+
+/// Rates every turn of `game` by replaying it from `start`, the word set
+/// before the first guess.
+pub fn rate_game(game: &Game, start: &WordSet) -> Vec<Rating> {
+    let mut set = start.clone();
+    game.turns()
+        .iter()
+        .map(|turn| {
+            let rating = rate(&set, &turn.guess, &turn.pattern);
+            set.reduce(&turn.guess, &turn.pattern);
+            rating
+        })
+        .collect()
+}
+
+/// The game the strategy of `start` plays against `secret`.
+pub fn solver_game(start: &WordSet, secret: Guess, max_guesses: usize) -> Game {
+    let mut game = Game::new(secret, start.clone());
+    game.set_max_guesses(max_guesses);
+    while game.status() == GameStatus::InProgress {
+        let guess = game.word_set().rank(1)[0].guess;
+        game.play(guess);
+    }
+    game
+}
+
+/// A whole game in a line: how many guesses, and the mean ratings.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GameSummary {
+    pub guesses: usize,
+    pub won: bool,
+    /// Mean skill of the guesses in the list, if any were.
+    pub skill: Option<f64>,
+    pub luck: f64,
+}
+
+impl GameSummary {
+    pub fn new(game: &Game, ratings: &[Rating]) -> GameSummary {
+        let skills: Vec<f64> = ratings.iter().filter_map(|rating| rating.skill).map(f64::from).collect();
+        let luck: f64 = ratings.iter().map(|rating| f64::from(rating.luck)).sum();
+        GameSummary {
+            guesses: game.turns().len(),
+            won: game.status() == GameStatus::Won,
+            skill: (!skills.is_empty()).then(|| skills.iter().sum::<f64>() / skills.len() as f64),
+            luck: luck / ratings.len().max(1) as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{guess, words};
+
+    #[test]
+    fn skill_is_relative_to_the_best_guess() {
+        let set = words();
+        let best = set.rank(1)[0].guess;
+        let reply = |word: &Guess| Pattern::score(word, &guess("hills"));
+        assert_eq!(rate(&set, &best, &reply(&best)).skill, Some(99));
+        let speed = rate(&set, &guess("speed"), &reply(&guess("speed")));
+        assert!(speed.skill.unwrap() < 99);
+        assert_eq!(rate(&set, &guess("zzzzz"), &reply(&guess("zzzzz"))).skill, None);
+    }
+
+    #[test]
+    fn luck_ranks_the_reply_among_the_others() {
+        let set = words();
+        // SPEED splits the answers 3 (BILLS, FILLS, HILLS), 2 and 1.
+        let speed = guess("speed");
+        assert_eq!(rate(&set, &speed, &Pattern::score(&speed, &guess("speed"))).luck, 91);
+        assert_eq!(rate(&set, &speed, &Pattern::score(&speed, &guess("hills"))).luck, 25);
+    }
+
+    #[test]
+    fn solver_is_summarised_alongside_the_player() {
+        let start = words();
+        let solver = solver_game(&start, guess("allay"), Game::MAX_GUESSES);
+        assert_eq!(solver.status(), GameStatus::Won);
+        let summary = GameSummary::new(&solver, &rate_game(&solver, &start));
+        assert_eq!(summary.guesses, solver.turns().len());
+        assert_eq!(summary.skill, Some(99.0));
+    }
+}
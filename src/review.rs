@@ -0,0 +1,80 @@
+use crate::feedback::Guess;
+use crate::game::{Game, Turn};
+use crate::suggestion::{GuessStats, WordSet};
+
+/// One turn of a game, looked at again with the solver.
+#[derive(Debug, Clone)]
+pub struct TurnReview {
+    pub turn: Turn,
+    /// What the solver would have played instead.
+    pub suggestion: GuessStats,
+    /// How the guess played scores by the same measures, when it was in the
+    /// guess list.
+    pub played: Option<GuessStats>,
+    /// The answers still possible after the turn.
+    pub remaining: Vec<Guess>,
+}
+
+impl TurnReview {
+    /// Bits of expected information given up by not playing the suggestion;
+    /// `None` for a guess outside the list.
+    pub fn shortfall(&self) -> Option<f64> {
+        let played = self.played.as_ref()?;
+        Some((self.suggestion.expected_info - played.expected_info).max(0.0))
+    }
+}
+
+/// Replays `game` from `start`, the word set before the first guess, asking
+/// its strategy at every turn what it would have played.
+///
+/// Every turn runs the strategy once, so a long game against a big list
+/// takes a while; call it off the UI thread. The review stops at a turn
+/// played with no answers left, where there is nothing to suggest.
+pub fn review(game: &Game, start: &WordSet) -> Vec<TurnReview> {
+    let mut set = start.clone();
+    game.turns()
+        .iter()
+        .map_while(|&turn| {
+            if set.answer_count() == 0 {
+                return None;
+            }
+            let suggestion = set.rank(1).into_iter().next()?;
+            let played = set.guess_stats(&turn.guess);
+            set.reduce(&turn.guess, &turn.pattern);
+            Some(TurnReview {
+                turn,
+                suggestion,
+                played,
+                remaining: set.answer_words().collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feedback::Pattern;
+    use crate::testing::{guess, words};
+
+    #[test]
+    fn every_turn_is_compared_with_the_solver() {
+        let start = words();
+        let mut game = Game::new(guess("hills"), start.clone());
+        game.play(guess("speed"));
+        game.play(guess("hills"));
+
+        let turns = review(&game, &start);
+        assert_eq!(turns.len(), 2);
+        assert_eq!((turns[0].turn.candidates_before, turns[0].turn.candidates_after), (6, 3));
+        assert_eq!(turns[0].remaining.len(), 3);
+        assert!(turns[0].shortfall().unwrap() > 0.0);
+        assert_eq!(turns[1].remaining, [guess("hills")]);
+
+        // Colours that fit no answer leave nothing to suggest after them.
+        let mut assist = Game::assist(start.clone());
+        assist.record(guess("speed"), Pattern::parse("ggggb").unwrap());
+        assist.record(guess("hills"), Pattern::parse("bbbbb").unwrap());
+        assert_eq!(review(&assist, &start).len(), 1);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::feedback::{Guess, Pattern};
+use crate::game::Game;
+use crate::suggestion::WordSet;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A game left unfinished, kept on disk so that it can be picked up again.
+///
+/// Only the guesses and their feedback are saved; the candidates, the
+/// keyboard and the rest are worked out again by replaying them, so the file
+/// stays small and doesn't depend on how the solver stores its state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// The format of the file; see [`SavedGame::VERSION`].
+    pub version: u32,
+    pub boards: Vec<SavedBoard>,
+    pub max_guesses: usize,
+    #[serde(default)]
+    pub hard_mode: bool,
+    /// The daily puzzle number, if the game was one.
+    #[serde(default)]
+    pub puzzle: Option<u64>,
+}
+
+/// One board of a saved game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedBoard {
+    /// The secret word, or `None` in an assist game.
+    pub secret: Option<Guess>,
+    pub guesses: Vec<Guess>,
+    pub patterns: Vec<Pattern>,
+}
+
+impl SavedGame {
+    /// The current format. Fields added later must have defaults, so that
+    /// files written by older versions still load; a file from a newer
+    /// version is refused rather than half understood.
+    pub const VERSION: u32 = 1;
+    /// How many boards a game may be played on at once.
+    pub const BOARD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+    /// Captures the turns played so far on every board.
+    pub fn new(games: &[Game], puzzle: Option<u64>) -> SavedGame {
+        SavedGame {
+            version: SavedGame::VERSION,
+            boards: games
+                .iter()
+                .map(|game| SavedBoard {
+                    secret: game.secret(),
+                    guesses: game.turns().iter().map(|turn| turn.guess).collect(),
+                    patterns: game.turns().iter().map(|turn| turn.pattern).collect(),
+                })
+                .collect(),
+            max_guesses: games.first().map_or(Game::MAX_GUESSES, Game::max_guesses),
+            hard_mode: games.first().is_some_and(|game| game.word_set().hard_mode().is_some()),
+            puzzle,
+        }
+    }
+    /// Reads the game saved at `path`, or `None` if there is none.
+    pub fn load(path: &Path) -> io::Result<Option<SavedGame>> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        };
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e));
+        let saved: SavedGame = serde_json::from_str(&text).map_err(|e| invalid(e.to_string()))?;
+        if saved.version > SavedGame::VERSION {
+            return Err(invalid(format!("saved by a newer version (format {})", saved.version)));
+        }
+        Ok(Some(saved))
+    }
+    /// Writes the game to `path`, replacing any earlier save whole: the new
+    /// file is written alongside and then renamed over the old one.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let partial = path.with_extension("partial");
+        fs::write(&partial, serde_json::to_string_pretty(self).unwrap())?;
+        fs::rename(&partial, path)
+    }
+    /// Deletes the save at `path`, if there is one.
+    pub fn remove(path: &Path) -> io::Result<()> {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+    /// Replays every board from `words`, the word set before the first
+    /// guess.
+    ///
+    /// A single board may have up to `max_guesses` guesses, and each extra
+    /// board one more; a save outside that, or with a board count that
+    /// can't be played, is damaged.
+    pub fn restore(&self, words: &WordSet, max_guesses: usize) -> Result<Vec<Game>, String> {
+        let damaged = || "the saved game is damaged".to_string();
+        if !SavedGame::BOARD_COUNTS.contains(&self.boards.len()) {
+            return Err(damaged());
+        }
+        if !(1..=max_guesses + self.boards.len() - 1).contains(&self.max_guesses) {
+            return Err(damaged());
+        }
+        let length = words.word_length();
+        self.boards
+            .iter()
+            .map(|board| {
+                let words_fit = board.secret.iter().chain(&board.guesses).all(|word| word.length() == length)
+                    && board.patterns.iter().all(|pattern| pattern.length() == length);
+                if !words_fit {
+                    return Err(format!("the saved game is not of {} letter words", length));
+                }
+                if board.guesses.len() != board.patterns.len() || board.guesses.len() > self.max_guesses {
+                    return Err(damaged());
+                }
+                let mut game = match board.secret {
+                    Some(secret) => Game::new(secret, words.clone()),
+                    None => Game::assist(words.clone()),
+                };
+                game.set_max_guesses(self.max_guesses);
+                game.set_hard_mode(self.hard_mode);
+                for (&guess, &pattern) in board.guesses.iter().zip(&board.patterns) {
+                    game.record(guess, pattern);
+                }
+                Ok(game)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{guess, words};
+
+    #[test]
+    fn saved_game_resumes_where_it_left_off() {
+        let mut game = Game::new(guess("hills"), words());
+        game.set_hard_mode(true);
+        game.play(guess("llama"));
+        let path = std::env::temp_dir().join(format!("wordle-save-{}.json", std::process::id()));
+        SavedGame::new(&[game.clone()], Some(12)).save(&path).unwrap();
+
+        let saved = SavedGame::load(&path).unwrap().unwrap();
+        assert_eq!((saved.puzzle, saved.hard_mode), (Some(12), true));
+        let restored = saved.restore(&words(), Game::MAX_GUESSES).unwrap();
+        assert_eq!(restored[0].turns(), game.turns());
+        assert_eq!(restored[0].word_set().answer_count(), game.word_set().answer_count());
+        assert_eq!(restored[0].check_hard_mode(&guess("speed")), game.check_hard_mode(&guess("speed")));
+
+        SavedGame::remove(&path).unwrap();
+        assert_eq!(SavedGame::load(&path).unwrap(), None);
+    }
+
+    #[test]
+    fn older_saves_load_and_newer_ones_are_refused() {
+        let path = std::env::temp_dir().join(format!("wordle-save-version-{}.json", std::process::id()));
+        fs::write(&path, r#"{"version":1,"boards":[{"secret":"hills","guesses":[],"patterns":[]}],"max_guesses":6}"#)
+            .unwrap();
+        let saved = SavedGame::load(&path).unwrap().unwrap();
+        assert_eq!((saved.hard_mode, saved.puzzle), (false, None));
+        fs::write(&path, r#"{"version":2,"boards":[],"max_guesses":6}"#).unwrap();
+        assert!(SavedGame::load(&path).unwrap_err().to_string().contains("newer version"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn damaged_saves_are_refused() {
+        let board = SavedBoard {
+            secret: Some(guess("hills")),
+            guesses: Vec::new(),
+            patterns: Vec::new(),
+        };
+        let saved = |boards: Vec<SavedBoard>, max_guesses| SavedGame {
+            version: SavedGame::VERSION,
+            boards,
+            max_guesses,
+            hard_mode: false,
+            puzzle: None,
+        };
+        let damaged = Some("the saved game is damaged".to_string());
+        assert_eq!(saved(Vec::new(), 6).restore(&words(), 6).err(), damaged);
+        assert_eq!(saved(vec![board.clone(); 3], 8).restore(&words(), 6).err(), damaged);
+        assert_eq!(saved(vec![board.clone()], 0).restore(&words(), 6).err(), damaged);
+        assert_eq!(saved(vec![board.clone()], 7).restore(&words(), 6).err(), damaged);
+        assert!(saved(vec![board; 2], 7).restore(&words(), 6).is_ok());
+    }
+}
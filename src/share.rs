@@ -0,0 +1,107 @@
+use crate::feedback::{Feedback, Pattern};
+use crate::game::{Game, GameStatus};
+
+/// The spoiler-free summary of a finished game that people post to each
+/// other: a header such as "Wordle 1,234 4/6" and a row of coloured squares
+/// per guess.
+///
+/// Several boards are scored together, "Quordle 5&7&8&9/9", and drawn two
+/// abreast, each padded with white squares below the guess that solved it.
+/// An asterisk marks a game in hard mode, as in the official game.
+pub fn share_text(games: &[Game], puzzle: Option<u64>) -> String {
+    let name = match games.len() {
+        2 => "Dordle",
+        4 => "Quordle",
+        8 => "Octordle",
+        _ => "Wordle",
+    };
+    let scores: Vec<String> = games
+        .iter()
+        .map(|game| match game.status() {
+            GameStatus::Won => game.turns().len().to_string(),
+            _ => "X".to_string(),
+        })
+        .collect();
+    let mut header = name.to_string();
+    if let Some(number) = puzzle {
+        header += &format!(" {}", thousands(number));
+    }
+    let max_guesses = games.first().map_or(Game::MAX_GUESSES, Game::max_guesses);
+    header += &format!(" {}/{}", scores.join("&"), max_guesses);
+    if games.iter().any(|game| game.word_set().hard_mode().is_some()) {
+        header.push('*');
+    }
+
+    let mut blocks = vec![header];
+    for pair in games.chunks(2) {
+        let rows = pair.iter().map(|game| game.turns().len()).max().unwrap_or(0);
+        let lines: Vec<String> = (0..rows)
+            .map(|row| {
+                let grids: Vec<String> = pair
+                    .iter()
+                    .map(|game| match game.turns().get(row) {
+                        Some(turn) => squares(&turn.pattern),
+                        None => "\u{2b1c}".repeat(game.word_set().word_length()),
+                    })
+                    .collect();
+                grids.join(" ")
+            })
+            .collect();
+        blocks.push(lines.join("\n"));
+    }
+    blocks.join("\n\n")
+}
+
+/// One guess as black, yellow and green squares.
+fn squares(pattern: &Pattern) -> String {
+    pattern
+        .feedback()
+        .iter()
+        .map(|feedback| match feedback {
+            Feedback::Absent => '\u{2b1b}',
+            Feedback::Present => '\u{1f7e8}',
+            Feedback::Correct => '\u{1f7e9}',
+        })
+        .collect()
+}
+
+/// `number` with commas between the thousands: 1,234.
+fn thousands(number: u64) -> String {
+    let digits = number.to_string();
+    let mut text = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            text.push(',');
+        }
+        text.push(c);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{guess, words};
+
+    fn game(secret: &str, guesses: &[&str]) -> Game {
+        let mut game = Game::new(guess(secret), words());
+        for word in guesses {
+            game.play(guess(word));
+        }
+        game
+    }
+
+    #[test]
+    fn results_are_shared_as_squares() {
+        let won = game("hills", &["llama", "hills"]);
+        assert_eq!(
+            share_text(std::slice::from_ref(&won), Some(1234)),
+            "Wordle 1,234 2/6\n\n\u{1f7e8}\u{1f7e8}\u{2b1b}\u{2b1b}\u{2b1b}\n\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}\u{1f7e9}"
+        );
+        let lost = game("fills", &["speed"; Game::MAX_GUESSES]);
+        let text = share_text(&[won, lost], None);
+        assert!(text.starts_with("Dordle 2&X/6\n\n"));
+        // The solved board is padded below its last guess.
+        assert_eq!(text.lines().nth(4).unwrap().chars().filter(|&c| c == '\u{2b1c}').count(), 5);
+    }
+}
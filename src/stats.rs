@@ -0,0 +1,211 @@
+use crate::feedback::{Guess, Pattern};
+use crate::game::{Game, GameStatus};
+use crate::rating::Rating;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// One finished game, as kept in the statistics file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// The local date the game finished on.
+    pub date: NaiveDate,
+    pub answer: Guess,
+    /// The daily puzzle number, if the game was one.
+    #[serde(default)]
+    pub puzzle: Option<u64>,
+    pub won: bool,
+    pub guesses: Vec<Guess>,
+    pub patterns: Vec<Pattern>,
+    /// The skill and luck of each guess, as shown beside the board. Lines
+    /// written before ratings had bits under `skill` and `luck` instead;
+    /// those are ignored and leave this empty.
+    #[serde(default)]
+    pub ratings: Vec<Rating>,
+}
+
+impl GameRecord {
+    /// Records a finished game with `ratings`, one per turn, as
+    /// [`rate_game`](crate::rate_game) gives them. `None` for a game still in
+    /// progress or without a secret.
+    pub fn new(game: &Game, ratings: Vec<Rating>, date: NaiveDate, puzzle: Option<u64>) -> Option<GameRecord> {
+        let answer = game.secret()?;
+        if game.status() == GameStatus::InProgress {
+            return None;
+        }
+        Some(GameRecord {
+            date,
+            answer,
+            puzzle,
+            won: game.status() == GameStatus::Won,
+            guesses: game.turns().iter().map(|turn| turn.guess).collect(),
+            patterns: game.turns().iter().map(|turn| turn.pattern).collect(),
+            ratings,
+        })
+    }
+    /// Checks a record read back from the file, which may have been edited
+    /// or cut short, for turns that don't add up.
+    fn check(&self) -> Result<(), String> {
+        if self.guesses.len() != self.patterns.len() {
+            return Err("guesses and patterns differ in number".to_string());
+        }
+        if self.won && self.guesses.is_empty() {
+            return Err("won without a guess".to_string());
+        }
+        if self.ratings.len() > self.guesses.len() {
+            return Err("more ratings than guesses".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Every game recorded so far, oldest first.
+///
+/// The file holds one JSON record per line, so finishing a game appends a
+/// line instead of writing the whole history out again.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    games: Vec<GameRecord>,
+    /// What was wrong with each line that couldn't be read.
+    skipped: Vec<String>,
+}
+
+impl History {
+    /// Reads the history from `path`; a file that doesn't exist yet is an
+    /// empty history. Lines that can't be read are left out, and listed in
+    /// [`History::skipped`], so one damaged game doesn't lose the rest.
+    pub fn load(path: &Path) -> io::Result<History> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        };
+        let mut history = History::default();
+        for (i, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let record = serde_json::from_str::<GameRecord>(line)
+                .map_err(|e| e.to_string())
+                .and_then(|record| record.check().map(|()| record));
+            match record {
+                Ok(record) => history.games.push(record),
+                Err(e) => history.skipped.push(format!("{}: line {}: {}", path.display(), i + 1, e)),
+            }
+        }
+        Ok(history)
+    }
+    /// Adds a game, and writes it to the end of the file at `path`.
+    pub fn append(&mut self, path: &Path, record: GameRecord) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(&record).unwrap())?;
+        self.games.push(record);
+        Ok(())
+    }
+    pub fn games(&self) -> &[GameRecord] {
+        &self.games
+    }
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+    pub fn played(&self) -> usize {
+        self.games.len()
+    }
+    /// Percentage of games won, rounded down as the official game does.
+    pub fn win_percent(&self) -> usize {
+        let wins = self.games.iter().filter(|game| game.won).count();
+        (100 * wins).checked_div(self.played()).unwrap_or(0)
+    }
+    /// Wins in a row up to the latest game.
+    pub fn current_streak(&self) -> usize {
+        self.games.iter().rev().take_while(|game| game.won).count()
+    }
+    pub fn max_streak(&self) -> usize {
+        self.games
+            .split(|game| !game.won)
+            .map(|streak| streak.len())
+            .max()
+            .unwrap_or(0)
+    }
+    /// How many games were won in each number of guesses, from one up to
+    /// `max_guesses` or the longest win, whichever is more.
+    pub fn distribution(&self, max_guesses: usize) -> Vec<usize> {
+        let longest = self.games.iter().filter(|game| game.won).map(|game| game.guesses.len());
+        let mut counts = vec![0; longest.max().unwrap_or(0).max(max_guesses)];
+        for game in self.games.iter().filter(|game| game.won) {
+            counts[game.guesses.len() - 1] += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rating::rate_game;
+    use crate::testing::{guess, words};
+
+    fn record(secret: &str, guesses: &[&str]) -> GameRecord {
+        let start = words();
+        let mut game = Game::new(guess(secret), start.clone());
+        game.set_max_guesses(guesses.len());
+        for word in guesses {
+            game.play(guess(word));
+        }
+        GameRecord::new(&game, rate_game(&game, &start), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), None).unwrap()
+    }
+
+    #[test]
+    fn games_are_scored_and_counted() {
+        let win = record("hills", &["llama", "hills"]);
+        assert!(win.won);
+        assert_eq!(win.patterns[1], Pattern::parse("ggggg").unwrap());
+        assert_eq!(win.ratings.len(), 2);
+        assert_eq!(win.ratings[1].skill, Some(99));
+
+        let loss = record("hills", &["speed"]);
+        let history = History {
+            games: vec![win.clone(), win.clone(), loss, win],
+            skipped: Vec::new(),
+        };
+        assert_eq!(history.played(), 4);
+        assert_eq!(history.win_percent(), 75);
+        assert_eq!((history.current_streak(), history.max_streak()), (1, 2));
+        assert_eq!(history.distribution(6), [0, 3, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn history_survives_a_restart() {
+        let path = std::env::temp_dir().join(format!("wordle-stats-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(history.played(), 0);
+        history.append(&path, record("fills", &["bills", "fills"])).unwrap();
+        history.append(&path, record("speed", &["speed"])).unwrap();
+        assert_eq!(History::load(&path).unwrap().games(), history.games());
+        // Lines from before ratings still load.
+        fs::write(
+            &path,
+            r#"{"date":"2024-03-01","answer":"hills","won":true,"guesses":["hills"],"patterns":["ggggg"],"skill":[2.5],"luck":[2.58]}"#,
+        )
+        .unwrap();
+        assert_eq!(History::load(&path).unwrap().games()[0].ratings, []);
+        // A damaged line is skipped and the rest still load.
+        fs::write(&path, "{}\n").unwrap();
+        history.append(&path, record("speed", &["speed"])).unwrap();
+        let damaged = History::load(&path).unwrap();
+        assert_eq!(damaged.played(), 1);
+        assert!(damaged.skipped()[0].contains("line 1"));
+        // So is a line that reads but whose turns don't add up.
+        fs::write(
+            &path,
+            concat!(r#"{"date":"2024-03-01","answer":"hills","won":true,"guesses":[],"patterns":[]}"#, "\n"),
+        )
+        .unwrap();
+        history.append(&path, record("speed", &["speed"])).unwrap();
+        let damaged = History::load(&path).unwrap();
+        assert_eq!(damaged.played(), 1);
+        assert!(damaged.skipped()[0].contains("won without a guess"));
+        assert_eq!(damaged.distribution(6), [1, 0, 0, 0, 0, 0]);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,273 @@
+use crate::lookahead::Lookahead;
+use crate::suggestion::{GuessStats, WordSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// An algorithm for ranking guesses against the remaining answers.
+pub trait Strategy: Send + Sync {
+    fn name(&self) -> &'static str;
+    /// The `n` best guesses for `set`, best first.
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats>;
+}
+
+/// Ranks every guess by `key`, lowest first. Ties go to guesses that could
+/// still be the answer and then to the guess listed first, so the order
+/// never depends on how the scoring was split between threads.
+pub(crate) fn rank_by(
+    set: &WordSet,
+    n: usize,
+    filter: impl Fn(&GuessStats) -> bool,
+    key: impl Fn(&GuessStats) -> f64,
+) -> Vec<GuessStats> {
+    let mut ranking: Vec<(usize, GuessStats)> = set
+        .all_stats()
+        .into_iter()
+        .filter(|(_, stats)| filter(stats))
+        .collect();
+    ranking.sort_by(|(i, a), (j, b)| {
+        key(a)
+            .total_cmp(&key(b))
+            .then(b.could_be_answer.cmp(&a.could_be_answer))
+            .then(i.cmp(j))
+    });
+    ranking.into_iter().take(n).map(|(_, stats)| stats).collect()
+}
+
+/// Ranks guesses for several boards played at once, as in Dordle and
+/// Quordle, by expected information summed over the boards. The other
+/// statistics are summed too, except that a guess could be the answer if it
+/// could be on any board.
+///
+/// Every board must share one pattern matrix; pass only the unsolved ones.
+pub fn rank_boards(boards: &[WordSet], n: usize) -> Vec<GuessStats> {
+    let Some(first) = boards.first() else {
+        return Vec::new();
+    };
+    let mut totals: Vec<(usize, Option<GuessStats>)> = vec![(0, None); first.matrix.guesses().len()];
+    for board in boards {
+        for (i, stats) in board.all_stats() {
+            let (count, total) = &mut totals[i];
+            *count += 1;
+            match total {
+                None => *total = Some(stats),
+                Some(total) => {
+                    total.expected_info += stats.expected_info;
+                    total.expected_remaining += stats.expected_remaining;
+                    total.worst_case += stats.worst_case;
+                    total.could_be_answer |= stats.could_be_answer;
+                }
+            }
+        }
+    }
+    // A guess ruled out by hard mode on any board can't be played at all.
+    let mut ranking: Vec<(usize, GuessStats)> = totals
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (count, total))| total.filter(|_| count == boards.len()).map(|total| (i, total)))
+        .collect();
+    ranking.sort_by(|(i, a), (j, b)| {
+        b.expected_info
+            .total_cmp(&a.expected_info)
+            .then(b.could_be_answer.cmp(&a.could_be_answer))
+            .then(i.cmp(j))
+    });
+    ranking.into_iter().take(n).map(|(_, stats)| stats).collect()
+}
+
+/// Maximise the expected information of the feedback.
+pub struct Entropy;
+
+impl Strategy for Entropy {
+    fn name(&self) -> &'static str {
+        "Entropy"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        rank_by(set, n, |_| true, |stats| -stats.expected_info)
+    }
+}
+
+/// Minimise the largest feedback bucket, i.e. the worst case.
+pub struct Minimax;
+
+impl Strategy for Minimax {
+    fn name(&self) -> &'static str {
+        "Minimax"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        // The bucket sizes are whole numbers, so the fraction only breaks ties.
+        rank_by(set, n, |_| true, |stats| {
+            stats.worst_case as f64 + stats.expected_remaining / (set.answer_count() as f64 + 1.0)
+        })
+    }
+}
+
+/// Minimise the expected number of answers left after the guess.
+pub struct ExpectedRemaining;
+
+impl Strategy for ExpectedRemaining {
+    fn name(&self) -> &'static str {
+        "Expected remaining"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        rank_by(set, n, |_| true, |stats| stats.expected_remaining)
+    }
+}
+
+/// Play the guess whose distinct letters appear in the most remaining
+/// answers, the way many people play by hand.
+pub struct LetterFrequency;
+
+impl Strategy for LetterFrequency {
+    fn name(&self) -> &'static str {
+        "Letter frequency"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        let mut counts = [0usize; 26];
+        for answer in set.answer_words() {
+            let mut seen = [false; 26];
+            for &c in answer.chars() {
+                let i = c as usize - 'a' as usize;
+                if !seen[i] {
+                    seen[i] = true;
+                    counts[i] += 1;
+                }
+            }
+        }
+        rank_by(set, n, |_| true, |stats| {
+            let mut seen = [false; 26];
+            let mut score = 0;
+            for &c in stats.guess.chars() {
+                let i = c as usize - 'a' as usize;
+                if !seen[i] {
+                    seen[i] = true;
+                    score += counts[i];
+                }
+            }
+            -(score as f64)
+        })
+    }
+}
+
+/// Maximise expected information, but only ever guess a possible answer.
+pub struct AnswersOnly;
+
+impl Strategy for AnswersOnly {
+    fn name(&self) -> &'static str {
+        "Answers only"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        rank_by(set, n, |stats| stats.could_be_answer, |stats| -stats.expected_info)
+    }
+}
+
+/// The built-in strategies, for front ends that let the player pick one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum StrategyKind {
+    #[default]
+    Entropy,
+    Minimax,
+    ExpectedRemaining,
+    LetterFrequency,
+    AnswersOnly,
+    Lookahead,
+}
+
+impl StrategyKind {
+    pub const ALL: [StrategyKind; 6] = [
+        StrategyKind::Entropy,
+        StrategyKind::Minimax,
+        StrategyKind::ExpectedRemaining,
+        StrategyKind::LetterFrequency,
+        StrategyKind::AnswersOnly,
+        StrategyKind::Lookahead,
+    ];
+
+    pub fn build(&self) -> Arc<dyn Strategy> {
+        match self {
+            StrategyKind::Entropy => Arc::new(Entropy),
+            StrategyKind::Minimax => Arc::new(Minimax),
+            StrategyKind::ExpectedRemaining => Arc::new(ExpectedRemaining),
+            StrategyKind::LetterFrequency => Arc::new(LetterFrequency),
+            StrategyKind::AnswersOnly => Arc::new(AnswersOnly),
+            StrategyKind::Lookahead => Arc::new(Lookahead::new(2, 10)),
+        }
+    }
+    /// Parses the names printed by `Display`, ignoring case, spaces and dashes.
+    pub fn parse(name: &str) -> Option<StrategyKind> {
+        let normalize = |s: &str| {
+            s.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase()
+        };
+        StrategyKind::ALL
+            .into_iter()
+            .find(|kind| normalize(&kind.to_string()) == normalize(name))
+    }
+}
+
+impl Display for StrategyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StrategyKind::Entropy => Entropy.name(),
+            StrategyKind::Minimax => Minimax.name(),
+            StrategyKind::ExpectedRemaining => ExpectedRemaining.name(),
+            StrategyKind::LetterFrequency => LetterFrequency.name(),
+            StrategyKind::AnswersOnly => AnswersOnly.name(),
+            StrategyKind::Lookahead => "Lookahead",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feedback::Guess;
+    use crate::matrix::PatternMatrix;
+    use crate::testing;
+
+    fn word_set(guesses: &[&str], answers: &[&str]) -> WordSet {
+        WordSet::new(Arc::new(PatternMatrix::new(testing::guesses(guesses), testing::guesses(answers))))
+    }
+
+    #[test]
+    fn strategies_disagree_where_expected() {
+        let set = word_set(
+            &["bills", "fills", "hills", "mills", "fibhm"],
+            &["bills", "fills", "hills", "mills"],
+        );
+        let best = |strategy: &dyn Strategy| strategy.rank(&set, 1)[0].guess.to_string();
+        assert_eq!(best(&Entropy), "fibhm");
+        assert_eq!(best(&Minimax), "fibhm");
+        assert_eq!(best(&ExpectedRemaining), "fibhm");
+        assert_eq!(best(&AnswersOnly), "bills");
+    }
+
+    #[test]
+    fn boards_are_ranked_together() {
+        let set = word_set(
+            &["bills", "fills", "hills", "mills", "fibhm"],
+            &["bills", "fills", "hills", "mills"],
+        );
+        let board = |answer: &str| {
+            let mut board = set.clone();
+            let (guess, answer) = (Guess::parse("bills").unwrap(), Guess::parse(answer).unwrap());
+            board.reduce(&guess, &crate::feedback::Pattern::score(&guess, &answer));
+            board
+        };
+        // Left with {fills, hills, mills} twice, where only fibhm splits.
+        let ranking = rank_boards(&[board("fills"), board("mills")], 2);
+        assert_eq!(ranking[0].guess.to_string(), "fibhm");
+        assert!((ranking[0].expected_info - 2.0 * 3f64.log2()).abs() < 1e-9);
+        assert!(ranking[1].could_be_answer);
+    }
+
+    #[test]
+    fn strategy_names_round_trip() {
+        for kind in StrategyKind::ALL {
+            assert_eq!(StrategyKind::parse(&kind.to_string()), Some(kind));
+        }
+        assert_eq!(StrategyKind::parse("expected-remaining"), Some(StrategyKind::ExpectedRemaining));
+    }
+}
@@ -1,107 +1,256 @@
-use std::collections::HashMap;
-use std::sync::LazyLock;
-use iced::Color;
-use crate::AnalysisFigure;
+use crate::feedback::{Guess, Pattern};
+use crate::hard::HardMode;
+use crate::matrix::PatternMatrix;
+use crate::strategy::{Entropy, Strategy};
+use crate::words::read_word_list;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+/// How a single guess would split the remaining answers.
+#[derive(Debug, Clone)]
+pub struct GuessStats {
+    pub guess: Guess,
+    /// Shannon entropy of the feedback, in bits.
+    pub expected_info: f64,
+    /// Mean number of answers left after playing the guess.
+    pub expected_remaining: f64,
+    /// Size of the largest feedback bucket.
+    pub worst_case: usize,
+    /// Whether the guess is itself one of the remaining answers.
+    pub could_be_answer: bool,
+    /// Expected guesses to solve, this one included. Only the lookahead
+    /// strategy fills this in.
+    pub expected_guesses: Option<f64>,
+}
+
+/// The answers still consistent with every guess made so far.
 #[derive(Clone)]
-pub(crate) struct WordSet {
-    pub words: Vec<String>,
-    pub answer_words: Vec<String>,
+pub struct WordSet {
+    pub(crate) matrix: Arc<PatternMatrix>,
+    pub(crate) answers: Vec<usize>,
+    strategy: Arc<dyn Strategy>,
+    /// Hints revealed so far, when only hard-mode-legal guesses may be played.
+    hard_mode: Option<HardMode>,
+    /// Set from another thread to call off a ranking in progress.
+    cancel: Option<Arc<AtomicBool>>,
 }
 
 impl WordSet {
-    pub fn suggest(&self) -> String {
-        if self.answer_words.len() == 1 {
-            return self.answer_words[0].clone().to_ascii_uppercase()
+    pub fn new(matrix: Arc<PatternMatrix>) -> WordSet {
+        let answers = (0..matrix.answers().len()).collect();
+        WordSet {
+            matrix,
+            answers,
+            strategy: Arc::new(Entropy),
+            hard_mode: None,
+            cancel: None,
         }
-        //let mut heuristic_table = HashMap::new();
-        let mut ans = "".to_string();
-        let mut peak_heuristic = 0.0;
-        for guess_word in &self.words {
-            let mut probability = [0; 3i32.pow(6) as usize];
-            for answer_word in &self.answer_words {
-                let mut bin_num = 0;
-                for i in 0..5 {
-                    if guess_word.chars().nth(i) == answer_word.chars().nth(i) {
-                        bin_num += 2 * (3u32).pow(i as u32);
-                    } else if answer_word.contains(guess_word.chars().nth(i).unwrap()) {
-                        bin_num += 1 * (3u32).pow(i as u32);
-                    }
-                }
-                probability[bin_num as usize] += 1;
-            }
-            let mut exp_info = 0.0;
-            for i in 0..(3i32.pow(6) as usize) {
-                let p = (probability[i] as f64) / (self.answer_words.len() as f64);
-                if probability[i] > 0 {exp_info += p * (1.0 / p).log(2.0)}
-            }
-            //heuristic_table.insert(guess_word.clone().to_ascii_uppercase(), exp_info);
-            if exp_info > peak_heuristic {
-                println!("{:.2}",&exp_info);
-                peak_heuristic = exp_info;
-                ans = guess_word.clone();
-            }
+    }
+    /// Loads the five letter words of a list, one word per line, using it for
+    /// both guesses and answers. The pattern matrix is cached next to the
+    /// list.
+    pub fn load(word_list: &Path) -> io::Result<WordSet> {
+        WordSet::load_lists(word_list, word_list, Guess::DEFAULT_LEN)
+    }
+    /// Loads the possible answers and the allowed guesses of `length` letters
+    /// from their own lists, reading a file only once when both are the same.
+    /// Every answer must also be an allowed guess.
+    pub fn load_lists(answer_list: &Path, guess_list: &Path, length: usize) -> io::Result<WordSet> {
+        let answers = read_word_list(answer_list, length)?;
+        let guesses = if guess_list == answer_list {
+            answers.clone()
+        } else {
+            read_word_list(guess_list, length)?
+        };
+        let allowed: HashSet<Guess> = guesses.iter().copied().collect();
+        if let Some(missing) = answers.iter().find(|answer| !allowed.contains(answer)) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("answer \"{}\" is not in {}", missing, guess_list.display()),
+            ));
         }
-        println!("Expected Info: {:.2}",peak_heuristic);
-        ans.to_ascii_uppercase()
-        //(ans.to_ascii_uppercase(), heuristic_table)
-    }
-    pub fn reduce(&mut self, grade_result: [(char, Color); 5]) {
-        let YELLOW = Color::from_rgb8(0xFF, 0xCE, 0x1B);
-        let GREEN = Color::from_rgb8(0x04, 0x63, 0x07);
-        let mut answer_set = Vec::new();
-        for word in &self.answer_words {
-            // println!("WORD: {}",&word);
-            let mut passes_check = true;
-            //if *word == *guess {passes_check = false;}
-            if word.chars().zip(grade_result).all(|(word_char, (guess_char, color))| word_char == guess_char) {passes_check = false;}
-            else {
-                for i in 0..5 {
-                    match grade_result[i].1 {
-                        color if color == GREEN => {
-                            if word.chars().nth(i) != Some(grade_result[i].0.to_ascii_lowercase()) {passes_check = false;break}
-                        },
-                        color if color == YELLOW => {
-                            if !word.contains(grade_result[i].0.to_ascii_lowercase()) ||
-                                word.chars().nth(i) == Some(grade_result[i].0.to_ascii_lowercase()) {passes_check = false;break}
-                        },
-                        _ => {
-                            if word.contains(grade_result[i].0.to_ascii_lowercase()) {passes_check = false;break}
-                        },
-                    }
-                }
-            }
-            if passes_check {answer_set.push(word.clone());}
-            //  if word.chars().zip(grade_result).all(|(word_char, (guess_char, color))|
-            // match color {
-            //     color if color == GREEN => word_char == guess_char,
-            //     color if color == YELLOW => word.contains(guess_char),
-            //     _ => !word.contains(guess_char)
-            // }) {answer_set.push(word.clone());}
+        let cache = PatternMatrix::cache_path(answer_list, guess_list, length);
+        Ok(WordSet::new(Arc::new(PatternMatrix::load_or_build(&cache, guesses, answers))))
+    }
+    /// Replaces the strategy used by [`WordSet::suggest`] and
+    /// [`WordSet::rank`]. Clones of this set keep the strategy they had.
+    pub fn set_strategy(&mut self, strategy: Arc<dyn Strategy>) {
+        self.strategy = strategy;
+    }
+    pub fn strategy(&self) -> &dyn Strategy {
+        self.strategy.as_ref()
+    }
+    /// Restricts suggestions to guesses that reuse every hint revealed from
+    /// now on. Turn it on before the first guess, as in the official game.
+    pub fn set_hard_mode(&mut self, on: bool) {
+        self.hard_mode = on.then(HardMode::new);
+    }
+    pub fn hard_mode(&self) -> Option<&HardMode> {
+        self.hard_mode.as_ref()
+    }
+    /// Lets a ranking be called off from another thread: once `flag` is set,
+    /// [`WordSet::rank`] stops scoring and returns early with a ranking that
+    /// means nothing and should be thrown away. Clones share the flag.
+    pub fn set_cancel(&mut self, flag: Arc<AtomicBool>) {
+        self.cancel = Some(flag);
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+    /// Letters in every word of the lists.
+    pub fn word_length(&self) -> usize {
+        self.matrix.length()
+    }
+    pub fn answer_count(&self) -> usize {
+        self.answers.len()
+    }
+    pub fn answer_words(&self) -> impl Iterator<Item = Guess> + '_ {
+        self.answers.iter().map(|&i| self.matrix.answers()[i])
+    }
+    /// The best guess according to the current [`Strategy`], or `None` if no
+    /// answers are left.
+    pub fn suggest(&self) -> Option<Guess> {
+        self.rank(1).first().map(|best| best.guess)
+    }
+    /// The `n` best guesses according to the current [`Strategy`].
+    pub fn rank(&self, n: usize) -> Vec<GuessStats> {
+        self.strategy.rank(self, n)
+    }
+    /// Statistics for every guess that may be played and its index, scored
+    /// in parallel.
+    pub(crate) fn all_stats(&self) -> Vec<(usize, GuessStats)> {
+        let guesses = self.matrix.guesses();
+        (0..guesses.len())
+            .into_par_iter()
+            .filter(|&i| !self.is_cancelled() && self.hard_mode.as_ref().is_none_or(|hard| hard.allows(&guesses[i])))
+            .map(|i| (i, self.stats(i)))
+            .collect()
+    }
+    /// Keeps only the answers that would have produced `pattern` for `guess`.
+    pub fn reduce(&mut self, guess: &Guess, pattern: &Pattern) {
+        if let Some(hard) = &mut self.hard_mode {
+            hard.add(guess, pattern);
         }
-        self.answer_words = answer_set;
-        //WordSet {words: self.words.clone(), answer_words: answer_set}
-    }
-    pub fn test_entry(&self, guess_word: String) -> AnalysisFigure {
-        if self.words.contains(&guess_word) {
-            let mut probability = [0; 3i32.pow(6) as usize];
-            for answer_word in &self.answer_words {
-                let mut bin_num = 0;
-                for i in 0..5 {
-                    if guess_word.chars().nth(i) == answer_word.chars().nth(i) {
-                        bin_num += 2 * (3u32).pow(i as u32);
-                    } else if answer_word.contains(guess_word.chars().nth(i).unwrap()) {
-                        bin_num += 1 * (3u32).pow(i as u32);
-                    }
-                }
-                probability[bin_num as usize] += 1;
+        let id = pattern.id();
+        match self.matrix.guess_index(guess) {
+            Some(g) => self.answers.retain(|&a| self.matrix.pattern(g, a) == id),
+            None => {
+                let answers = self.matrix.answers();
+                self.answers.retain(|&a| Pattern::score(guess, &answers[a]).id() == id)
             }
-            let mut exp_info = 0.0;
-            for i in 0..(3i32.pow(6) as usize) {
-                let p = (probability[i] as f64) / (self.answer_words.len() as f64);
-                if probability[i] > 0 {exp_info += p * (1.0 / p).log(2.0)}
+        }
+    }
+    /// Whether `guess` is in the allowed-guess list.
+    pub fn is_allowed(&self, guess: &Guess) -> bool {
+        self.matrix.guess_index(guess).is_some()
+    }
+    /// Expected information in bits of playing `guess`, or `None` if it is
+    /// not in the guess list.
+    pub fn test_entry(&self, guess: &Guess) -> Option<f64> {
+        self.guess_stats(guess).map(|stats| stats.expected_info)
+    }
+    pub fn guess_stats(&self, guess: &Guess) -> Option<GuessStats> {
+        self.matrix.guess_index(guess).map(|g| self.stats(g))
+    }
+    fn stats(&self, guess: usize) -> GuessStats {
+        let row = self.matrix.row(guess);
+        let mut probability = vec![0usize; Pattern::count(self.word_length())];
+        for &a in &self.answers {
+            probability[row[a] as usize] += 1;
+        }
+        let total = self.answers.len() as f64;
+        let mut exp_info = 0.0;
+        let mut exp_remaining = 0.0;
+        for &count in &probability {
+            let p = (count as f64) / total;
+            if count > 0 {
+                exp_info += p * (1.0 / p).log(2.0);
+                exp_remaining += p * count as f64;
             }
-            AnalysisFigure::Active(exp_info)
-        } else {AnalysisFigure::Unknown}
+        }
+        GuessStats {
+            guess: self.matrix.guesses()[guess],
+            expected_info: exp_info,
+            expected_remaining: exp_remaining,
+            worst_case: probability.iter().copied().max().unwrap_or(0),
+            // Only the answer itself scores all green.
+            could_be_answer: probability[probability.len() - 1] > 0,
+            expected_guesses: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::word_set;
+
+    fn remaining(set: &WordSet) -> Vec<String> {
+        set.answer_words().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn reduce_keeps_grey_letter_that_is_green_elsewhere() {
+        let mut set = word_set(&["steal", "steed", "spend", "sleet"]);
+        let guess = Guess::parse("speed").unwrap();
+        let answer = Guess::parse("steal").unwrap();
+        set.reduce(&guess, &Pattern::score(&guess, &answer));
+        assert_eq!(remaining(&set), ["steal"]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn reduce_keeps_the_answer_once_solved() {
+        let mut set = word_set(&["llama", "allay", "eerie"]);
+        let guess = Guess::parse("llama").unwrap();
+        set.reduce(&guess, &Pattern::score(&guess, &guess));
+        assert_eq!(remaining(&set), ["llama"]);
+    }
+
+    #[test]
+    fn rank_reports_bucket_statistics() {
+        let set = word_set(&["bills", "fills", "hills", "llama"]);
+        let ranking = set.rank(4);
+        assert_eq!(ranking.len(), 4);
+        let llama = ranking.iter().find(|stats| stats.guess.to_string() == "llama").unwrap();
+        assert_eq!(llama.worst_case, 3);
+        assert!(llama.could_be_answer);
+        assert!((llama.expected_remaining - 2.5).abs() < 1e-9);
+        assert!(ranking.windows(2).all(|w| w[0].expected_info >= w[1].expected_info));
+    }
+
+    #[test]
+    fn hard_mode_only_suggests_legal_guesses() {
+        let answers: Vec<Guess> = ["bills", "fills", "hills", "mills"].iter().map(|word| Guess::parse(word).unwrap()).collect();
+        let mut guesses = answers.clone();
+        guesses.push(Guess::parse("fibhm").unwrap());
+        let mut set = WordSet::new(Arc::new(PatternMatrix::new(guesses, answers)));
+        let bills = Guess::parse("bills").unwrap();
+        let pattern = Pattern::parse("bgggg").unwrap();
+        let mut easy = set.clone();
+        easy.reduce(&bills, &pattern);
+        assert_eq!(easy.suggest(), Guess::parse("fibhm"));
+        set.set_hard_mode(true);
+        set.reduce(&bills, &pattern);
+        assert_eq!(set.suggest(), Guess::parse("fills"));
+    }
+
+    #[test]
+    fn cancelled_ranking_stops_early() {
+        let mut set = word_set(&["bills", "fills", "hills", "mills"]);
+        let cancel = Arc::new(AtomicBool::new(false));
+        set.set_cancel(cancel.clone());
+        assert_eq!(set.rank(4).len(), 4);
+        cancel.store(true, Ordering::Relaxed);
+        assert!(set.rank(4).is_empty());
+    }
+
+    #[test]
+    fn suggest_breaks_ties_by_list_order() {
+        let set = word_set(&["bills", "fills", "hills", "mills"]);
+        assert_eq!(set.suggest(), Guess::parse("bills"));
+    }
+}
@@ -0,0 +1,30 @@
+//! Words and word sets shared by the unit tests.
+
+use crate::feedback::Guess;
+use crate::matrix::PatternMatrix;
+use crate::suggestion::WordSet;
+use std::sync::Arc;
+
+/// A small list whose words share letters in awkward ways: three differ only
+/// in the first letter, two repeat L and A in different places, and one has
+/// a double E.
+pub(crate) const WORDS: [&str; 6] = ["bills", "fills", "hills", "llama", "allay", "speed"];
+
+pub(crate) fn guess(word: &str) -> Guess {
+    Guess::parse(word).unwrap()
+}
+
+pub(crate) fn guesses(words: &[&str]) -> Vec<Guess> {
+    words.iter().map(|word| guess(word)).collect()
+}
+
+/// A word set with `words` as both the guesses and the answers.
+pub(crate) fn word_set(words: &[&str]) -> WordSet {
+    let words = guesses(words);
+    WordSet::new(Arc::new(PatternMatrix::new(words.clone(), words)))
+}
+
+/// The word set of [`WORDS`].
+pub(crate) fn words() -> WordSet {
+    word_set(&WORDS)
+}
@@ -0,0 +1,227 @@
+use crate::feedback::{Guess, Pattern};
+use crate::strategy::{Entropy, Strategy};
+use crate::suggestion::{GuessStats, WordSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One guess of a solving strategy, and where to go for each reply.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub guess: Guess,
+    /// How many answers reach this node, the guess itself included.
+    pub answers: usize,
+    /// The next node for every reply other than all green.
+    pub children: BTreeMap<Pattern, Node>,
+}
+
+/// The complete set of guesses a strategy would play, for every answer, from
+/// a fixed opener.
+///
+/// Building it runs the strategy once per node; playing it back afterwards
+/// is a walk from the root with no search at all.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTree {
+    root: Node,
+}
+
+impl DecisionTree {
+    /// Follows the strategy of `set` from `opener` for every answer in it.
+    pub fn build(set: &WordSet, opener: Guess) -> DecisionTree {
+        DecisionTree {
+            root: build_node(set, opener),
+        }
+    }
+    pub fn root(&self) -> &Node {
+        &self.root
+    }
+    /// The guesses played, in order, to solve `answer`, or `None` if the tree
+    /// does not cover it.
+    pub fn path(&self, answer: &Guess) -> Option<Vec<Guess>> {
+        let mut node = &self.root;
+        let mut guesses = vec![node.guess];
+        loop {
+            let pattern = Pattern::score(&node.guess, answer);
+            if pattern.is_solved() {
+                return Some(guesses);
+            }
+            node = node.children.get(&pattern)?;
+            guesses.push(node.guess);
+        }
+    }
+    /// The node for the position `set` is in, assuming every earlier guess
+    /// came from this tree.
+    fn lookup(&self, set: &WordSet) -> Option<&Node> {
+        let answer = set.answer_words().next()?;
+        let mut node = &self.root;
+        while node.answers > set.answer_count() {
+            node = node.children.get(&Pattern::score(&node.guess, &answer))?;
+        }
+        (node.answers == set.answer_count()).then_some(node)
+    }
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+    pub fn from_json(json: &str) -> serde_json::Result<DecisionTree> {
+        serde_json::from_str(json)
+    }
+    /// One line per answer, alternating each guess with the reply it got:
+    ///
+    /// ```text
+    /// tares bybbb lions ggggg
+    /// ```
+    ///
+    /// The last reply is all green, with one `g` per letter.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        write_paths(&self.root, &mut String::new(), &mut text);
+        text
+    }
+    pub fn from_text(text: &str) -> Result<DecisionTree, String> {
+        let mut root: Option<Node> = None;
+        for (line_num, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let error = |message: &str| format!("line {}: {}", line_num + 1, message);
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() % 2 != 0 {
+                return Err(error("expected guess and reply pairs"));
+            }
+            let mut steps = Vec::new();
+            for pair in tokens.chunks(2) {
+                let guess = Guess::parse(pair[0]).ok_or_else(|| error("invalid guess"))?;
+                let pattern = Pattern::parse(pair[1]).ok_or_else(|| error("invalid reply"))?;
+                let length = steps.first().map_or(guess.length(), |(first, _): &(Guess, Pattern)| first.length());
+                if guess.length() != length || pattern.length() != length {
+                    return Err(error("words and replies differ in length"));
+                }
+                steps.push((guess, pattern));
+            }
+            if !steps.last().unwrap().1.is_solved() {
+                return Err(error("path does not end in all green"));
+            }
+            let (first, _) = steps[0];
+            let mut node = root.get_or_insert_with(|| Node {
+                guess: first,
+                answers: 0,
+                children: BTreeMap::new(),
+            });
+            for (i, &(guess, pattern)) in steps.iter().enumerate() {
+                if node.guess != guess {
+                    return Err(error("guess disagrees with an earlier line"));
+                }
+                node.answers += 1;
+                if pattern.is_solved() {
+                    if i + 1 != steps.len() {
+                        return Err(error("path continues after all green"));
+                    }
+                    break;
+                }
+                let (next, _) = *steps.get(i + 1).ok_or_else(|| error("path ends early"))?;
+                node = node.children.entry(pattern).or_insert_with(|| Node {
+                    guess: next,
+                    answers: 0,
+                    children: BTreeMap::new(),
+                });
+            }
+        }
+        root.map(|root| DecisionTree { root }).ok_or_else(|| "empty decision tree".to_string())
+    }
+}
+
+/// Plays the tree back. Positions the tree does not cover, because an
+/// earlier guess came from elsewhere, fall back to [`Entropy`].
+impl Strategy for DecisionTree {
+    fn name(&self) -> &'static str {
+        "Decision tree"
+    }
+    fn rank(&self, set: &WordSet, n: usize) -> Vec<GuessStats> {
+        match self.lookup(set).and_then(|node| set.guess_stats(&node.guess)) {
+            Some(stats) => vec![stats],
+            None => Entropy.rank(set, n),
+        }
+    }
+}
+
+fn build_node(set: &WordSet, guess: Guess) -> Node {
+    let replies: BTreeSet<Pattern> = set
+        .answer_words()
+        .map(|answer| Pattern::score(&guess, &answer))
+        .filter(|pattern| !pattern.is_solved())
+        .collect();
+    let children = replies
+        .into_iter()
+        .map(|pattern| {
+            let mut child = set.clone();
+            child.reduce(&guess, &pattern);
+            let next = next_guess(&child);
+            (pattern, build_node(&child, next))
+        })
+        .collect();
+    Node {
+        guess,
+        answers: set.answer_count(),
+        children,
+    }
+}
+
+/// The strategy's choice, unless it would not split the answers at all, in
+/// which case guessing one of them is the only way to make progress.
+fn next_guess(set: &WordSet) -> Guess {
+    match set.rank(1).first() {
+        Some(best) if best.worst_case < set.answer_count() || best.could_be_answer => best.guess,
+        _ => set.answer_words().next().unwrap(),
+    }
+}
+
+fn write_paths(node: &Node, prefix: &mut String, text: &mut String) {
+    let start = prefix.len();
+    prefix.push_str(&node.guess.to_string());
+    if node.answers > node.children.values().map(|child| child.answers).sum() {
+        text.push_str(prefix);
+        text.push_str(&format!(" {}\n", "g".repeat(node.guess.length())));
+    }
+    for (pattern, child) in &node.children {
+        let len = prefix.len();
+        prefix.push_str(&format!(" {} ", pattern));
+        write_paths(child, prefix, text);
+        prefix.truncate(len);
+    }
+    prefix.truncate(start);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::word_set;
+
+    #[test]
+    fn tree_solves_every_answer_and_round_trips() {
+        let set = word_set(&["bills", "fills", "hills", "mills", "llama", "allay", "speed", "steal"]);
+        let tree = DecisionTree::build(&set, Guess::parse("speed").unwrap());
+        assert_eq!(tree.root().answers, 8);
+        for answer in set.answer_words() {
+            assert_eq!(tree.path(&answer).unwrap().last(), Some(&answer));
+        }
+        assert_eq!(DecisionTree::from_json(&tree.to_json()).unwrap(), tree);
+        assert_eq!(DecisionTree::from_text(&tree.to_text()).unwrap(), tree);
+    }
+
+    #[test]
+    fn trees_of_other_lengths_round_trip() {
+        let set = word_set(&["rats", "star", "tars", "arts", "soap"]);
+        let tree = DecisionTree::build(&set, Guess::parse("rats").unwrap());
+        let text = tree.to_text();
+        assert!(text.lines().all(|line| line.ends_with(" gggg")));
+        assert_eq!(DecisionTree::from_text(&text).unwrap(), tree);
+    }
+
+    #[test]
+    fn tree_plays_back_as_a_strategy() {
+        let mut set = word_set(&["bills", "fills", "hills", "mills", "llama", "allay"]);
+        let tree = DecisionTree::build(&set, Guess::parse("llama").unwrap());
+        assert_eq!(tree.rank(&set, 1)[0].guess.to_string(), "llama");
+        let answer = Guess::parse("hills").unwrap();
+        for step in tree.path(&answer).unwrap().windows(2) {
+            set.reduce(&step[0], &Pattern::score(&step[0], &answer));
+            assert_eq!(tree.rank(&set, 1)[0].guess, step[1]);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::feedback::Guess;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Reads the words of `length` letters from a list, one word per line; see
+/// [`parse_word_list`].
+pub fn read_word_list(path: &Path, length: usize) -> io::Result<Vec<Guess>> {
+    let text = fs::read_to_string(path).map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    parse_word_list(&text, length).map_err(|e| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))
+    })
+}
+
+/// Parses the words of `length` letters from a list, one word per line, in
+/// the order given.
+///
+/// Blank lines and words of other lengths are skipped, so one list can serve
+/// every length. The rest must be in lower case and listed once; the first
+/// line that isn't is reported by number, rather than quietly left out of
+/// the game.
+pub fn parse_word_list(text: &str, length: usize) -> Result<Vec<Guess>, String> {
+    if !(Guess::MIN_LEN..=Guess::MAX_LEN).contains(&length) {
+        return Err(format!("words must have {} to {} letters", Guess::MIN_LEN, Guess::MAX_LEN));
+    }
+    let mut words = Vec::new();
+    let mut seen: HashMap<Guess, usize> = HashMap::new();
+    for (i, line) in text.lines().enumerate() {
+        let (number, word) = (i + 1, line.trim());
+        if word.chars().count() != length {
+            continue;
+        }
+        if !word.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(if word.chars().all(|c| c.is_ascii_alphabetic()) {
+                format!("line {}: \"{}\" is not in lower case", number, word)
+            } else {
+                format!("line {}: \"{}\" is not a word", number, word)
+            });
+        }
+        let guess = Guess::parse(word).unwrap();
+        if let Some(first) = seen.insert(guess, number) {
+            return Err(format!("line {}: \"{}\" is already listed on line {}", number, word, first));
+        }
+        words.push(guess);
+    }
+    if words.is_empty() {
+        return Err(format!("no {} letter words", length));
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_list_is_validated() {
+        let words = parse_word_list("which\n\nthere\n", 5).unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(parse_word_list("Which", 5).unwrap_err(), "line 1: \"Which\" is not in lower case");
+        assert_eq!(parse_word_list("wh1ch", 5).unwrap_err(), "line 1: \"wh1ch\" is not a word");
+        assert_eq!(
+            parse_word_list("which\nthere\nwhich", 5).unwrap_err(),
+            "line 3: \"which\" is already listed on line 1"
+        );
+    }
+
+    #[test]
+    fn word_list_is_filtered_by_length() {
+        let words = parse_word_list("which\nwhich's\nrats\nbetween\nstar", 4).unwrap();
+        assert_eq!(words.iter().map(|word| word.to_string()).collect::<Vec<_>>(), ["rats", "star"]);
+        assert_eq!(parse_word_list("which\nthere", 6).unwrap_err(), "no 6 letter words");
+    }
+}